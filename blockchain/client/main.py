@@ -1,4 +1,7 @@
 from typing import Optional
+import base64
+import copy
+import struct
 import uvicorn
 
 from fastapi import FastAPI, Body, Depends, HTTPException,  File, UploadFile
@@ -40,13 +43,98 @@ app.add_middleware(
 # Solana Client
 client = HotaSolanaRPC(programId, False, "devnet")
 
+# Borsh stores floats little-endian, HotaFloat64 packs them big-endian
+class HotaFloat64LE(HotaFloat64):
+    def value(self):
+        return struct.unpack("<d", bytes(self.serialize()))[0]
+
+    def setValue(self, inFloat):
+        inArray = list(struct.pack("<d", inFloat))
+        for i in range(8):
+            self.get("value").set(i, inArray[i])
+
+# Borsh Option<T>: a 0 tag for None, or a 1 tag followed by the value
+class HotaOption(BaseStruct):
+    def __init__(self, inner: BaseStruct):
+        super().__init__([BaseElement("tag", HotaUint8(0)), BaseElement("value", inner)])
+
+    def serialize(self):
+        if self.get("tag").value() == 0:
+            return [0]
+        return super().serialize()
+
+    def struct2object(self):
+        if self.get("tag").value() == 0:
+            return None
+        return self.get("value").struct2object()
+
+    def object2struct(self, object):
+        if object is None:
+            self.get("tag").setValue(0)
+            return
+        self.get("tag").setValue(1)
+        fill_struct(self.get("value"), object)
+
+# Fill nested structs from plain dicts, leaf by leaf
+def fill_struct(data: BaseStruct, object):
+    if isinstance(object, dict):
+        for key, value in object.items():
+            fill_struct(data.get(key), value)
+    else:
+        data.object2struct(object)
+
+# Instances of a struct class share their fields, copy one to get separate fields
+def new_struct(StructClass):
+    return copy.deepcopy(StructClass())
+
 # Solana instruction data
 @BaseStructClass
 class Coordinates:
-    latitude=HotaFloat64()
-    longitude=HotaFloat64()
+    latitude=HotaFloat64LE()
+    longitude=HotaFloat64LE()
+
+@BaseStructClass
+class EnvironmentRange:
+    temperature_min=HotaFloat64LE()
+    temperature_max=HotaFloat64LE()
+    humidity_min=HotaFloat64LE()
+    humidity_max=HotaFloat64LE()
+
+@BaseStructClass
+class SensorReading:
+    temperature=HotaFloat64LE()
+    humidity=HotaFloat64LE()
+
+@BaseStructClass
+class GoodsClassification:
+    hs_code=HotaUint32()
+    hazmat_class=HotaUint8()
 
 # Solana account data
+@BaseStructClass
+class EnvironmentLog:
+    reading_count=HotaUint32()
+    excursion_count=HotaUint32()
+    temperature_min=HotaFloat64LE()
+    temperature_max=HotaFloat64LE()
+    humidity_min=HotaFloat64LE()
+    humidity_max=HotaFloat64LE()
+    last_reading_time=HotaIntX(8)
+
+@BaseStructClass
+class SubLocation:
+    zone=HotaStringUTF16(8)
+    aisle=HotaUint16()
+    rack=HotaUint16()
+    shelf=HotaUint16()
+    bin=HotaUint16()
+
+@BaseStructClass
+class ReceivingHours:
+    utc_offset_minutes=HotaIntX(2)
+    open_minutes=HotaArrayStruct(7, lambda: HotaUint16())
+    close_minutes=HotaArrayStruct(7, lambda: HotaUint16())
+
 @BaseStructClass
 class Depot:
     owner=HotaPublicKey()
@@ -54,6 +142,44 @@ class Depot:
     info=HotaStringUTF16(256)
     coordinates=Coordinates()
     time_created=HotaIntX(8)
+    organization=HotaPublicKey()
+    organization_index=HotaUint32()
+    verified_by=HotaPublicKey()
+    verification_time=HotaIntX(8)
+    verification_expiry=HotaIntX(8)
+    credential_ref=HotaHex(32)
+    verification_revoked=HotaUint8()
+    require_inspection_before_dispatch=HotaUint8()
+    boundary_required_documents=HotaUint8()
+    jurisdiction_code=HotaHex(6)
+    licensed_hazmat_classes=HotaUint16()
+    hazmat_licensed_by=HotaPublicKey()
+    max_item_count=HotaUint64()
+    max_weight_grams=HotaUint64()
+    max_volume_cm3=HotaUint64()
+    item_count=HotaUint64()
+    total_weight_grams=HotaUint64()
+    total_volume_cm3=HotaUint64()
+    stocktake_count=HotaUint32()
+    last_stocktake_time=HotaIntX(8)
+    last_stocktake_discrepancy=HotaIntX(8)
+    parent_depot=HotaPublicKey()
+    pending_parent_depot=HotaPublicKey()
+    child_count=HotaUint32()
+    children_item_count=HotaUint64()
+    children_weight_grams=HotaUint64()
+    children_volume_cm3=HotaUint64()
+    reported_item_count=HotaUint64()
+    reported_weight_grams=HotaUint64()
+    reported_volume_cm3=HotaUint64()
+    depot_type=HotaUint8()
+    has_receiving_hours=HotaUint8()
+    receiving_hours=ReceivingHours()
+    reject_after_hours=HotaUint8()
+    after_hours_receipt_count=HotaUint64()
+    owner_epoch=HotaUint32()
+    parent_link_epoch=HotaUint32()
+    organization_owner_epoch=HotaUint32()
 
 @BaseStructClass
 class Items:
@@ -64,6 +190,56 @@ class Items:
     coordinates=Coordinates()
     cur_time=HotaIntX(8)
     time_created=HotaIntX(8)
+    expiry_time=HotaIntX(8)
+    has_environment_range=HotaUint8()
+    environment_range=EnvironmentRange()
+    environment_log=EnvironmentLog()
+    non_compliant=HotaUint8()
+    last_updated_by=HotaPublicKey()
+    tag_pubkey=HotaPublicKey()
+    tag_scan_count=HotaUint64()
+    last_scan_time=HotaIntX(8)
+    last_scan_coordinates=new_struct(Coordinates)
+    last_transfer_time=HotaIntX(8)
+    require_verified_destination=HotaUint8()
+    frozen=HotaUint8()
+    freeze_reason=HotaUint16()
+    frozen_by=HotaPublicKey()
+    frozen_at=HotaIntX(8)
+    freeze_record_count=HotaUint32()
+    inspection_count=HotaUint32()
+    last_inspection_verdict=HotaUint8()
+    last_inspection_depot=HotaPublicKey()
+    last_inspection_time=HotaIntX(8)
+    origin_depot=HotaPublicKey()
+    transfer_count=HotaUint32()
+    document_count=HotaUint32()
+    pending_document_types=HotaUint8()
+    classification=GoodsClassification()
+    weight_grams=HotaUint64()
+    volume_cm3=HotaUint64()
+    sub_location=SubLocation()
+    sub_location_updated_by=HotaPublicKey()
+    sub_location_time=HotaIntX(8)
+    received_after_hours=HotaUint8()
+    carrier=HotaPublicKey()
+    carrier_pickup_depot=HotaPublicKey()
+    carrier_pickup_time=HotaIntX(8)
+    has_route_plan=HotaUint8()
+    destination_depot=HotaPublicKey()
+    delivery_deadline=HotaIntX(8)
+    delivery_status=HotaUint8()
+    arrival_time=HotaIntX(8)
+    lateness_seconds=HotaIntX(8)
+    overdue_marked_at=HotaIntX(8)
+    consignee=HotaPublicKey()
+    delivered=HotaUint8()
+    delivered_at=HotaIntX(8)
+    delivery_coordinates=new_struct(Coordinates)
+    has_delivery_note=HotaUint8()
+    delivery_note_hash=HotaHex(32)
+    compliance_override_count=HotaUint32()
+    last_compliance_override_time=HotaIntX(8)
 
 # Solana instruction
 @BaseInstructionDataClass("init_depot")
@@ -79,14 +255,47 @@ class InitItemsInstruction:
     info=HotaStringUTF16(256)
     coordinates=Coordinates()
     seed_random=HotaUintX(16)
+    expiry_time=HotaIntX(8)
+    environment_range=HotaOption(EnvironmentRange())
+    tag_pubkey=HotaPublicKey()
+    classification=GoodsClassification()
+    weight_grams=HotaUint64()
+    volume_cm3=HotaUint64()
+    consignee=HotaPublicKey()
 
 @BaseInstructionDataClass("update_items")
 class UpdateItemsInstruction:
     coordinates=Coordinates()
+    sensor_reading=HotaOption(SensorReading())
 
 @BaseInstructionDataClass("transfer_items")
 class TransferItemsInstruction:
-    pass
+    override_compliance=HotaUint8()
+
+# Program addresses
+def find_config_pubkey():
+    return findProgramAddress(createBytesFromArrayBytes(
+        "config".encode("utf-8"),
+    ), client.program_id)
+
+def find_transfer_rules_pubkey():
+    return findProgramAddress(createBytesFromArrayBytes(
+        "transfer_rules".encode("utf-8"),
+    ), client.program_id)
+
+# Anchor passes the program id in place of an optional account that is left out
+def optional_pubkey(public_key: Optional[str]):
+    if public_key is None:
+        return client.program_id
+    return PublicKey(public_key)
+
+# Accounts are allocated at their Rust size, which is larger than the Borsh data
+def get_account_struct(public_key: PublicKey, AccountDataClass):
+    account_info = client.get_account_info(public_key)
+    account_data_bytes = base64.b64decode(account_info.data[0])
+    account_data = AccountDataClass()
+    account_data.deserialize(account_data_bytes[8:8 + account_data.size()])
+    return account_data.struct2object()
 
 ##### Router
 
@@ -94,6 +303,20 @@ class CoordinatesModel(BaseModel):
     latitude: float
     longitude: float
 
+class EnvironmentRangeModel(BaseModel):
+    temperature_min: float
+    temperature_max: float
+    humidity_min: float
+    humidity_max: float
+
+class SensorReadingModel(BaseModel):
+    temperature: float
+    humidity: float
+
+class GoodsClassificationModel(BaseModel):
+    hs_code: int = 0
+    hazmat_class: int = 0
+
 # init_depot
 class InitDepotModel(BaseModel):
     owner_private_key: str
@@ -128,6 +351,7 @@ async def init_depot(
             [
                 makePublicKey(sysvar_clock),
                 makeKeyPair(payerPrivateKey).public_key,
+                find_config_pubkey(),
                 owner_keypair.public_key,
                 depot_pubkey,
                 makePublicKey(sysvar_rent),
@@ -155,6 +379,13 @@ class InitItemsModel(BaseModel):
     name: str
     info: str
     coordinates: CoordinatesModel
+    expiry_time: int = 0
+    environment_range: Optional[EnvironmentRangeModel] = None
+    tag_public_key: Optional[str] = None
+    classification: GoodsClassificationModel = GoodsClassificationModel()
+    weight_grams: int = 0
+    volume_cm3: int = 0
+    consignee_public_key: Optional[str] = None
 
 @app.post("/init-items")
 async def init_items(
@@ -171,6 +402,15 @@ async def init_items(
         instruction_data.get("name").object2struct(initItemsModel.name)
         instruction_data.get("info").object2struct(initItemsModel.info)
         instruction_data.get("seed_random").random()
+        instruction_data.get("expiry_time").object2struct(initItemsModel.expiry_time)
+        instruction_data.get("environment_range").object2struct(
+            initItemsModel.environment_range.model_dump() if initItemsModel.environment_range else None
+        )
+        instruction_data.get("tag_pubkey").object2struct(PublicKey(initItemsModel.tag_public_key).byte_value if initItemsModel.tag_public_key else bytes(32))
+        fill_struct(instruction_data.get("classification"), initItemsModel.classification.model_dump())
+        instruction_data.get("weight_grams").object2struct(initItemsModel.weight_grams)
+        instruction_data.get("volume_cm3").object2struct(initItemsModel.volume_cm3)
+        instruction_data.get("consignee").object2struct(PublicKey(initItemsModel.consignee_public_key).byte_value if initItemsModel.consignee_public_key else bytes(32))
 
         items_pubkey = findProgramAddress(createBytesFromArrayBytes(
             owner_keypair.public_key.byte_value,
@@ -184,9 +424,12 @@ async def init_items(
             [
                 makePublicKey(sysvar_clock),
                 makeKeyPair(payerPrivateKey).public_key,
+                find_config_pubkey(),
                 owner_keypair.public_key,
                 depot_owner_keypair.public_key,
                 depot_pubkey,
+                optional_pubkey(None),
+                optional_pubkey(None),
                 items_pubkey,
                 makePublicKey(sysvar_rent),
                 makePublicKey(system_program),
@@ -211,6 +454,8 @@ class UpdateItemsModel(BaseModel):
     owner_private_key: str
     items_public_key: str
     coordinates: CoordinatesModel
+    sensor_reading: Optional[SensorReadingModel] = None
+    route_plan_public_key: Optional[str] = None
 
 @app.post("/update-items")
 async def update_items(
@@ -223,16 +468,19 @@ async def update_items(
         instruction_data = UpdateItemsInstruction()
         instruction_data.get("coordinates").get("latitude").object2struct(updateItemsModel.coordinates.latitude)
         instruction_data.get("coordinates").get("longitude").object2struct(updateItemsModel.coordinates.longitude)
+        instruction_data.get("sensor_reading").object2struct(
+            updateItemsModel.sensor_reading.model_dump() if updateItemsModel.sensor_reading else None
+        )
 
         instruction_address = client.send_transaction(
             instruction_data,
             [
                 makePublicKey(sysvar_clock),
                 makeKeyPair(payerPrivateKey).public_key,
+                find_config_pubkey(),
                 owner_keypair.public_key,
                 items_pubkey,
-                makePublicKey(sysvar_rent),
-                makePublicKey(system_program),
+                optional_pubkey(updateItemsModel.route_plan_public_key),
             ],
             [
                 makeKeyPair(payerPrivateKey),
@@ -255,6 +503,10 @@ class TransferItemsModel(BaseModel):
     new_depot_owner_private_key: str
     new_depot_public_key: str
     items_public_key: str
+    override_compliance: bool = False
+    organization_public_key: Optional[str] = None
+    partner_agreement_public_key: Optional[str] = None
+    new_depot_accreditation_authority_public_key: Optional[str] = None
 
 @app.post("/transfer-items")
 async def transfer_items(
@@ -269,19 +521,27 @@ async def transfer_items(
         items_pubkey = PublicKey(transferItemsModel.items_public_key)
 
         instruction_data = TransferItemsInstruction()
+        instruction_data.get("override_compliance").object2struct(int(transferItemsModel.override_compliance))
 
         instruction_address = client.send_transaction(
             instruction_data,
             [
                 makePublicKey(sysvar_clock),
                 makeKeyPair(payerPrivateKey).public_key,
+                find_config_pubkey(),
+                find_transfer_rules_pubkey(),
                 old_depot_owner_keypair.public_key,
                 new_depot_owner_keypair.public_key,
                 old_depot_pubkey,
                 new_depot_pubkey,
+                optional_pubkey(None),
+                optional_pubkey(None),
+                optional_pubkey(None),
+                optional_pubkey(None),
+                optional_pubkey(transferItemsModel.organization_public_key),
+                optional_pubkey(transferItemsModel.partner_agreement_public_key),
+                optional_pubkey(transferItemsModel.new_depot_accreditation_authority_public_key),
                 items_pubkey,
-                makePublicKey(sysvar_rent),
-                makePublicKey(system_program),
             ],
             [
                 makeKeyPair(payerPrivateKey),
@@ -321,14 +581,14 @@ async def get_items_info(public_key: str):
 @app.get("/get-depot-data")
 async def get_depot_data(public_key: str):
     def fun():
-        res: dict = client.get_account_data(PublicKey(public_key), Depot, [8, 0])
+        res: dict = get_account_struct(PublicKey(public_key), Depot)
         return res
     return make_response_auto_catch(fun)
 
 @app.get("/get-items-data")
 async def get_items_data(public_key: str):
     def fun():
-        res: dict = client.get_account_data(PublicKey(public_key), Items, [8, 0])
+        res: dict = get_account_struct(PublicKey(public_key), Items)
         return res
     return make_response_auto_catch(fun)
 
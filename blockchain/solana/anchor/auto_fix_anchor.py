@@ -103,8 +103,22 @@ def fix_program_rs(content: str) -> str:
         cnt_sum += cnt
     return content, cnt_sum
 
+# The anchor program is maintained directly, see seahorse/main.py.
+# Only freshly generated Seahorse output still calls ctx.bumps.get(...),
+# so refuse to rewrite anything else.
+def is_seahorse_output(prefix: str = '') -> bool:
+    path = os.path.join(prefix, 'lib.rs')
+    if not os.path.exists(path):
+        return False
+    with open(path, 'r') as file:
+        return 'ctx.bumps.get(' in file.read()
+
 # Read file and fix content
 def main(prefix: str = ''):
+    if not is_seahorse_output(prefix):
+        print('lib.rs is not fresh Seahorse output, the anchor program is maintained directly. Nothing fixed')
+        return
+
     fix_object = {
         'lib.rs': fix_lib_rs,
         'dot/program.rs': fix_program_rs,
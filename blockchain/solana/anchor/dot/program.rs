@@ -6,12 +6,204 @@ use anchor_lang::{prelude::*, solana_program};
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use std::{cell::RefCell, rc::Rc};
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+seahorse_const! { TAG_SCAN_MAX_AGE, 300 }
+
+seahorse_const! { HANDOFF_RECEIPT_DOMAIN, b"electra_chain:handoff_receipt" }
+
+seahorse_const! { ROLE_ADMIN, 1 }
+
+seahorse_const! { ROLE_RECEIVER, 2 }
+
+seahorse_const! { ROLE_SHIPPER, 4 }
+
+seahorse_const! { ROLE_AUDITOR, 8 }
+
+seahorse_const! { MULTISIG_MAX_OWNERS, 10 }
+
+seahorse_const! { ORGANIZATION_MAX_ADMINS, 8 }
+
+seahorse_const! { EARTH_RADIUS_METERS, 6371000.0 }
+
+seahorse_const! { TRANSFER_RULES_MAX, 16 }
+
+seahorse_const! { HS_CODE_DIGITS, 6 }
+
+seahorse_const! { HAZMAT_CLASS_MAX, 9 }
+
+seahorse_const! { ROUTE_PLAN_MAX_WAYPOINTS, 16 }
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default, PartialEq)]
 pub struct Coordinates {
     pub lat: f64,
     pub long: f64,
 }
 
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct EnvironmentRange {
+    pub temperature_min: f64,
+    pub temperature_max: f64,
+    pub humidity_min: f64,
+    pub humidity_max: f64,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct SensorReading {
+    pub temperature: f64,
+    pub humidity: f64,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct HandoffReceipt {
+    pub items: Pubkey,
+    pub old_depot: Pubkey,
+    pub new_depot: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct EnvironmentLog {
+    pub reading_count: u32,
+    pub excursion_count: u32,
+    pub temperature_min: f64,
+    pub temperature_max: f64,
+    pub humidity_min: f64,
+    pub humidity_max: f64,
+    pub last_reading_time: i64,
+}
+
+// Proposals are sized for their largest action, so boxing saves no space
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub enum ProposalAction {
+    EditDepot {
+        name_array: [u16; 32],
+        info_array: [u16; 256],
+        coordinates_class: Coordinates,
+    },
+    ChangeDepotOwner {
+        new_owner: Pubkey,
+    },
+    AcceptTransfer {
+        items: Pubkey,
+        old_depot: Pubkey,
+    },
+    DispatchTransfer {
+        items: Pubkey,
+        new_depot: Pubkey,
+        override_compliance: bool,
+    },
+    GrantRole {
+        delegate: Pubkey,
+        permissions: u8,
+    },
+    RevokeRole {
+        delegate: Pubkey,
+    },
+    SetOwners {
+        owners: [Pubkey; 10],
+        owner_count: u8,
+        threshold: u8,
+    },
+}
+
+impl Default for ProposalAction {
+    fn default() -> Self {
+        ProposalAction::ChangeDepotOwner {
+            new_owner: Pubkey::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, Copy)]
+pub enum InspectionVerdict {
+    Pass,
+    Fail,
+    Conditional,
+}
+
+impl Default for InspectionVerdict {
+    fn default() -> Self {
+        InspectionVerdict::Pass
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, Copy)]
+pub enum DocumentType {
+    BillOfLading,
+    Invoice,
+    CustomsDeclaration,
+    PackingList,
+    Certificate,
+    Other,
+}
+
+impl Default for DocumentType {
+    fn default() -> Self {
+        DocumentType::Other
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct GoodsClassification {
+    pub hs_code: u32,
+    pub hazmat_class: u8,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default, Copy)]
+pub struct TransferRule {
+    pub origin_code: [u8; 6],
+    pub destination_code: [u8; 6],
+    pub hs_prefix: u32,
+    pub hs_prefix_digits: u8,
+    pub hazmat_class: u8,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct SubLocation {
+    pub zone_array: [u16; 8],
+    pub aisle: u16,
+    pub rack: u16,
+    pub shelf: u16,
+    pub bin: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, Copy)]
+pub enum DepotType {
+    Warehouse,
+    RetailStore,
+    Port,
+    Farm,
+    CrossDock,
+}
+
+impl Default for DepotType {
+    fn default() -> Self {
+        DepotType::Warehouse
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, Default)]
+pub struct ReceivingHours {
+    pub utc_offset_minutes: i16,
+    pub open_minutes: [u16; 7],
+    pub close_minutes: [u16; 7],
+}
+
+#[derive(Clone, Debug, PartialEq, AnchorSerialize, AnchorDeserialize, Copy)]
+pub enum DeliveryStatus {
+    Unset,
+    Pending,
+    Overdue,
+    OnTime,
+    Late,
+}
+
+impl Default for DeliveryStatus {
+    fn default() -> Self {
+        DeliveryStatus::Unset
+    }
+}
+
 #[account]
 #[derive(Debug)]
 pub struct Depot {
@@ -20,6 +212,44 @@ pub struct Depot {
     pub info_array: [u16; 256],
     pub coordinates_class: Coordinates,
     pub time_created: i64,
+    pub organization: Pubkey,
+    pub organization_index: u32,
+    pub verified_by: Pubkey,
+    pub verification_time: i64,
+    pub verification_expiry: i64,
+    pub credential_ref: [u8; 32],
+    pub verification_revoked: bool,
+    pub require_inspection_before_dispatch: bool,
+    pub boundary_required_documents: u8,
+    pub jurisdiction_code: [u8; 6],
+    pub licensed_hazmat_classes: u16,
+    pub hazmat_licensed_by: Pubkey,
+    pub max_item_count: u64,
+    pub max_weight_grams: u64,
+    pub max_volume_cm3: u64,
+    pub item_count: u64,
+    pub total_weight_grams: u64,
+    pub total_volume_cm3: u64,
+    pub stocktake_count: u32,
+    pub last_stocktake_time: i64,
+    pub last_stocktake_discrepancy: i64,
+    pub parent_depot: Pubkey,
+    pub pending_parent_depot: Pubkey,
+    pub child_count: u32,
+    pub children_item_count: u64,
+    pub children_weight_grams: u64,
+    pub children_volume_cm3: u64,
+    pub reported_item_count: u64,
+    pub reported_weight_grams: u64,
+    pub reported_volume_cm3: u64,
+    pub depot_type: DepotType,
+    pub has_receiving_hours: bool,
+    pub receiving_hours_class: ReceivingHours,
+    pub reject_after_hours: bool,
+    pub after_hours_receipt_count: u64,
+    pub owner_epoch: u32,
+    pub parent_link_epoch: u32,
+    pub organization_owner_epoch: u32,
 }
 
 impl<'info, 'entrypoint> Depot {
@@ -34,6 +264,44 @@ impl<'info, 'entrypoint> Depot {
             Mutable::new(account.coordinates_class.clone());
 
         let time_created = account.time_created;
+        let organization = account.organization.clone();
+        let organization_index = account.organization_index;
+        let verified_by = account.verified_by.clone();
+        let verification_time = account.verification_time;
+        let verification_expiry = account.verification_expiry;
+        let credential_ref = Mutable::new(account.credential_ref.clone());
+        let verification_revoked = account.verification_revoked;
+        let require_inspection_before_dispatch = account.require_inspection_before_dispatch;
+        let boundary_required_documents = account.boundary_required_documents;
+        let jurisdiction_code = Mutable::new(account.jurisdiction_code.clone());
+        let licensed_hazmat_classes = account.licensed_hazmat_classes;
+        let hazmat_licensed_by = account.hazmat_licensed_by.clone();
+        let max_item_count = account.max_item_count;
+        let max_weight_grams = account.max_weight_grams;
+        let max_volume_cm3 = account.max_volume_cm3;
+        let item_count = account.item_count;
+        let total_weight_grams = account.total_weight_grams;
+        let total_volume_cm3 = account.total_volume_cm3;
+        let stocktake_count = account.stocktake_count;
+        let last_stocktake_time = account.last_stocktake_time;
+        let last_stocktake_discrepancy = account.last_stocktake_discrepancy;
+        let parent_depot = account.parent_depot.clone();
+        let pending_parent_depot = account.pending_parent_depot.clone();
+        let child_count = account.child_count;
+        let children_item_count = account.children_item_count;
+        let children_weight_grams = account.children_weight_grams;
+        let children_volume_cm3 = account.children_volume_cm3;
+        let reported_item_count = account.reported_item_count;
+        let reported_weight_grams = account.reported_weight_grams;
+        let reported_volume_cm3 = account.reported_volume_cm3;
+        let depot_type = account.depot_type;
+        let has_receiving_hours = account.has_receiving_hours;
+        let receiving_hours_class = Mutable::new(account.receiving_hours_class.clone());
+        let reject_after_hours = account.reject_after_hours;
+        let after_hours_receipt_count = account.after_hours_receipt_count;
+        let owner_epoch = account.owner_epoch;
+        let parent_link_epoch = account.parent_link_epoch;
+        let organization_owner_epoch = account.organization_owner_epoch;
 
         Mutable::new(LoadedDepot {
             __account__: account,
@@ -43,6 +311,44 @@ impl<'info, 'entrypoint> Depot {
             info_array,
             coordinates_class,
             time_created,
+            organization,
+            organization_index,
+            verified_by,
+            verification_time,
+            verification_expiry,
+            credential_ref,
+            verification_revoked,
+            require_inspection_before_dispatch,
+            boundary_required_documents,
+            jurisdiction_code,
+            licensed_hazmat_classes,
+            hazmat_licensed_by,
+            max_item_count,
+            max_weight_grams,
+            max_volume_cm3,
+            item_count,
+            total_weight_grams,
+            total_volume_cm3,
+            stocktake_count,
+            last_stocktake_time,
+            last_stocktake_discrepancy,
+            parent_depot,
+            pending_parent_depot,
+            child_count,
+            children_item_count,
+            children_weight_grams,
+            children_volume_cm3,
+            reported_item_count,
+            reported_weight_grams,
+            reported_volume_cm3,
+            depot_type,
+            has_receiving_hours,
+            receiving_hours_class,
+            reject_after_hours,
+            after_hours_receipt_count,
+            owner_epoch,
+            parent_link_epoch,
+            organization_owner_epoch,
         })
     }
 
@@ -67,6 +373,158 @@ impl<'info, 'entrypoint> Depot {
         let time_created = loaded.time_created;
 
         loaded.__account__.time_created = time_created;
+
+        let organization = loaded.organization.clone();
+
+        loaded.__account__.organization = organization;
+
+        let organization_index = loaded.organization_index;
+
+        loaded.__account__.organization_index = organization_index;
+
+        let verified_by = loaded.verified_by.clone();
+
+        loaded.__account__.verified_by = verified_by;
+
+        let verification_time = loaded.verification_time;
+
+        loaded.__account__.verification_time = verification_time;
+
+        let verification_expiry = loaded.verification_expiry;
+
+        loaded.__account__.verification_expiry = verification_expiry;
+
+        let credential_ref = loaded.credential_ref.borrow().clone();
+
+        loaded.__account__.credential_ref = credential_ref;
+
+        let verification_revoked = loaded.verification_revoked;
+
+        loaded.__account__.verification_revoked = verification_revoked;
+
+        let require_inspection_before_dispatch = loaded.require_inspection_before_dispatch;
+
+        loaded.__account__.require_inspection_before_dispatch = require_inspection_before_dispatch;
+
+        let boundary_required_documents = loaded.boundary_required_documents;
+
+        loaded.__account__.boundary_required_documents = boundary_required_documents;
+
+        let jurisdiction_code = loaded.jurisdiction_code.borrow().clone();
+
+        loaded.__account__.jurisdiction_code = jurisdiction_code;
+
+        let licensed_hazmat_classes = loaded.licensed_hazmat_classes;
+
+        loaded.__account__.licensed_hazmat_classes = licensed_hazmat_classes;
+
+        let hazmat_licensed_by = loaded.hazmat_licensed_by.clone();
+
+        loaded.__account__.hazmat_licensed_by = hazmat_licensed_by;
+
+        let max_item_count = loaded.max_item_count;
+
+        loaded.__account__.max_item_count = max_item_count;
+
+        let max_weight_grams = loaded.max_weight_grams;
+
+        loaded.__account__.max_weight_grams = max_weight_grams;
+
+        let max_volume_cm3 = loaded.max_volume_cm3;
+
+        loaded.__account__.max_volume_cm3 = max_volume_cm3;
+
+        let item_count = loaded.item_count;
+
+        loaded.__account__.item_count = item_count;
+
+        let total_weight_grams = loaded.total_weight_grams;
+
+        loaded.__account__.total_weight_grams = total_weight_grams;
+
+        let total_volume_cm3 = loaded.total_volume_cm3;
+
+        loaded.__account__.total_volume_cm3 = total_volume_cm3;
+
+        let stocktake_count = loaded.stocktake_count;
+
+        loaded.__account__.stocktake_count = stocktake_count;
+
+        let last_stocktake_time = loaded.last_stocktake_time;
+
+        loaded.__account__.last_stocktake_time = last_stocktake_time;
+
+        let last_stocktake_discrepancy = loaded.last_stocktake_discrepancy;
+
+        loaded.__account__.last_stocktake_discrepancy = last_stocktake_discrepancy;
+
+        let parent_depot = loaded.parent_depot.clone();
+
+        loaded.__account__.parent_depot = parent_depot;
+
+        let pending_parent_depot = loaded.pending_parent_depot.clone();
+
+        loaded.__account__.pending_parent_depot = pending_parent_depot;
+
+        let child_count = loaded.child_count;
+
+        loaded.__account__.child_count = child_count;
+
+        let children_item_count = loaded.children_item_count;
+
+        loaded.__account__.children_item_count = children_item_count;
+
+        let children_weight_grams = loaded.children_weight_grams;
+
+        loaded.__account__.children_weight_grams = children_weight_grams;
+
+        let children_volume_cm3 = loaded.children_volume_cm3;
+
+        loaded.__account__.children_volume_cm3 = children_volume_cm3;
+
+        let reported_item_count = loaded.reported_item_count;
+
+        loaded.__account__.reported_item_count = reported_item_count;
+
+        let reported_weight_grams = loaded.reported_weight_grams;
+
+        loaded.__account__.reported_weight_grams = reported_weight_grams;
+
+        let reported_volume_cm3 = loaded.reported_volume_cm3;
+
+        loaded.__account__.reported_volume_cm3 = reported_volume_cm3;
+
+        let depot_type = loaded.depot_type;
+
+        loaded.__account__.depot_type = depot_type;
+
+        let has_receiving_hours = loaded.has_receiving_hours;
+
+        loaded.__account__.has_receiving_hours = has_receiving_hours;
+
+        let receiving_hours_class = loaded.receiving_hours_class.borrow().clone();
+
+        loaded.__account__.receiving_hours_class = receiving_hours_class;
+
+        let reject_after_hours = loaded.reject_after_hours;
+
+        loaded.__account__.reject_after_hours = reject_after_hours;
+
+        let after_hours_receipt_count = loaded.after_hours_receipt_count;
+
+        loaded.__account__.after_hours_receipt_count = after_hours_receipt_count;
+
+        let owner_epoch = loaded.owner_epoch;
+
+        loaded.__account__.owner_epoch = owner_epoch;
+
+        let parent_link_epoch = loaded.parent_link_epoch;
+
+        loaded.__account__.parent_link_epoch = parent_link_epoch;
+
+        let organization_owner_epoch = loaded.organization_owner_epoch;
+
+        loaded.__account__.organization_owner_epoch = organization_owner_epoch;
     }
 }
 
@@ -79,6 +537,44 @@ pub struct LoadedDepot<'info, 'entrypoint> {
     pub info_array: Mutable<[u16; 256]>,
     pub coordinates_class: Mutable<Coordinates>,
     pub time_created: i64,
+    pub organization: Pubkey,
+    pub organization_index: u32,
+    pub verified_by: Pubkey,
+    pub verification_time: i64,
+    pub verification_expiry: i64,
+    pub credential_ref: Mutable<[u8; 32]>,
+    pub verification_revoked: bool,
+    pub require_inspection_before_dispatch: bool,
+    pub boundary_required_documents: u8,
+    pub jurisdiction_code: Mutable<[u8; 6]>,
+    pub licensed_hazmat_classes: u16,
+    pub hazmat_licensed_by: Pubkey,
+    pub max_item_count: u64,
+    pub max_weight_grams: u64,
+    pub max_volume_cm3: u64,
+    pub item_count: u64,
+    pub total_weight_grams: u64,
+    pub total_volume_cm3: u64,
+    pub stocktake_count: u32,
+    pub last_stocktake_time: i64,
+    pub last_stocktake_discrepancy: i64,
+    pub parent_depot: Pubkey,
+    pub pending_parent_depot: Pubkey,
+    pub child_count: u32,
+    pub children_item_count: u64,
+    pub children_weight_grams: u64,
+    pub children_volume_cm3: u64,
+    pub reported_item_count: u64,
+    pub reported_weight_grams: u64,
+    pub reported_volume_cm3: u64,
+    pub depot_type: DepotType,
+    pub has_receiving_hours: bool,
+    pub receiving_hours_class: Mutable<ReceivingHours>,
+    pub reject_after_hours: bool,
+    pub after_hours_receipt_count: u64,
+    pub owner_epoch: u32,
+    pub parent_link_epoch: u32,
+    pub organization_owner_epoch: u32,
 }
 
 #[account]
@@ -91,6 +587,56 @@ pub struct Items {
     pub coordinates_class: Coordinates,
     pub cur_time: i64,
     pub time_created: i64,
+    pub expiry_time: i64,
+    pub has_environment_range: bool,
+    pub environment_range_class: EnvironmentRange,
+    pub environment_log_class: EnvironmentLog,
+    pub non_compliant: bool,
+    pub last_updated_by: Pubkey,
+    pub tag_pubkey: Pubkey,
+    pub tag_scan_count: u64,
+    pub last_scan_time: i64,
+    pub last_scan_coordinates_class: Coordinates,
+    pub last_transfer_time: i64,
+    pub require_verified_destination: bool,
+    pub frozen: bool,
+    pub freeze_reason: u16,
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+    pub freeze_record_count: u32,
+    pub inspection_count: u32,
+    pub last_inspection_verdict: InspectionVerdict,
+    pub last_inspection_depot: Pubkey,
+    pub last_inspection_time: i64,
+    pub origin_depot: Pubkey,
+    pub transfer_count: u32,
+    pub document_count: u32,
+    pub pending_document_types: u8,
+    pub classification_class: GoodsClassification,
+    pub weight_grams: u64,
+    pub volume_cm3: u64,
+    pub sub_location_class: SubLocation,
+    pub sub_location_updated_by: Pubkey,
+    pub sub_location_time: i64,
+    pub received_after_hours: bool,
+    pub carrier: Pubkey,
+    pub carrier_pickup_depot: Pubkey,
+    pub carrier_pickup_time: i64,
+    pub has_route_plan: bool,
+    pub destination_depot: Pubkey,
+    pub delivery_deadline: i64,
+    pub delivery_status: DeliveryStatus,
+    pub arrival_time: i64,
+    pub lateness_seconds: i64,
+    pub overdue_marked_at: i64,
+    pub consignee: Pubkey,
+    pub delivered: bool,
+    pub delivered_at: i64,
+    pub delivery_coordinates_class: Coordinates,
+    pub has_delivery_note: bool,
+    pub delivery_note_hash: [u8; 32],
+    pub compliance_override_count: u32,
+    pub last_compliance_override_time: i64,
 }
 
 impl<'info, 'entrypoint> Items {
@@ -107,6 +653,60 @@ impl<'info, 'entrypoint> Items {
 
         let cur_time = account.cur_time;
         let time_created = account.time_created;
+        let expiry_time = account.expiry_time;
+        let has_environment_range = account.has_environment_range;
+        let environment_range_class =
+            Mutable::new(account.environment_range_class.clone());
+
+        let environment_log_class =
+            Mutable::new(account.environment_log_class.clone());
+
+        let non_compliant = account.non_compliant;
+        let last_updated_by = account.last_updated_by.clone();
+        let tag_pubkey = account.tag_pubkey.clone();
+        let tag_scan_count = account.tag_scan_count;
+        let last_scan_time = account.last_scan_time;
+        let last_scan_coordinates_class = Mutable::new(account.last_scan_coordinates_class.clone());
+        let last_transfer_time = account.last_transfer_time;
+        let require_verified_destination = account.require_verified_destination;
+        let frozen = account.frozen;
+        let freeze_reason = account.freeze_reason;
+        let frozen_by = account.frozen_by.clone();
+        let frozen_at = account.frozen_at;
+        let freeze_record_count = account.freeze_record_count;
+        let inspection_count = account.inspection_count;
+        let last_inspection_verdict = account.last_inspection_verdict;
+        let last_inspection_depot = account.last_inspection_depot.clone();
+        let last_inspection_time = account.last_inspection_time;
+        let origin_depot = account.origin_depot.clone();
+        let transfer_count = account.transfer_count;
+        let document_count = account.document_count;
+        let pending_document_types = account.pending_document_types;
+        let classification_class = Mutable::new(account.classification_class.clone());
+        let weight_grams = account.weight_grams;
+        let volume_cm3 = account.volume_cm3;
+        let sub_location_class = Mutable::new(account.sub_location_class.clone());
+        let sub_location_updated_by = account.sub_location_updated_by.clone();
+        let sub_location_time = account.sub_location_time;
+        let received_after_hours = account.received_after_hours;
+        let carrier = account.carrier.clone();
+        let carrier_pickup_depot = account.carrier_pickup_depot.clone();
+        let carrier_pickup_time = account.carrier_pickup_time;
+        let has_route_plan = account.has_route_plan;
+        let destination_depot = account.destination_depot.clone();
+        let delivery_deadline = account.delivery_deadline;
+        let delivery_status = account.delivery_status;
+        let arrival_time = account.arrival_time;
+        let lateness_seconds = account.lateness_seconds;
+        let overdue_marked_at = account.overdue_marked_at;
+        let consignee = account.consignee.clone();
+        let delivered = account.delivered;
+        let delivered_at = account.delivered_at;
+        let delivery_coordinates_class = Mutable::new(account.delivery_coordinates_class.clone());
+        let has_delivery_note = account.has_delivery_note;
+        let delivery_note_hash = Mutable::new(account.delivery_note_hash.clone());
+        let compliance_override_count = account.compliance_override_count;
+        let last_compliance_override_time = account.last_compliance_override_time;
 
         Mutable::new(LoadedItems {
             __account__: account,
@@ -118,6 +718,56 @@ impl<'info, 'entrypoint> Items {
             coordinates_class,
             cur_time,
             time_created,
+            expiry_time,
+            has_environment_range,
+            environment_range_class,
+            environment_log_class,
+            non_compliant,
+            last_updated_by,
+            tag_pubkey,
+            tag_scan_count,
+            last_scan_time,
+            last_scan_coordinates_class,
+            last_transfer_time,
+            require_verified_destination,
+            frozen,
+            freeze_reason,
+            frozen_by,
+            frozen_at,
+            freeze_record_count,
+            inspection_count,
+            last_inspection_verdict,
+            last_inspection_depot,
+            last_inspection_time,
+            origin_depot,
+            transfer_count,
+            document_count,
+            pending_document_types,
+            classification_class,
+            weight_grams,
+            volume_cm3,
+            sub_location_class,
+            sub_location_updated_by,
+            sub_location_time,
+            received_after_hours,
+            carrier,
+            carrier_pickup_depot,
+            carrier_pickup_time,
+            has_route_plan,
+            destination_depot,
+            delivery_deadline,
+            delivery_status,
+            arrival_time,
+            lateness_seconds,
+            overdue_marked_at,
+            consignee,
+            delivered,
+            delivered_at,
+            delivery_coordinates_class,
+            has_delivery_note,
+            delivery_note_hash,
+            compliance_override_count,
+            last_compliance_override_time,
         })
     }
 
@@ -150,130 +800,6908 @@ impl<'info, 'entrypoint> Items {
         let time_created = loaded.time_created;
 
         loaded.__account__.time_created = time_created;
-    }
-}
 
-#[derive(Debug)]
-pub struct LoadedItems<'info, 'entrypoint> {
-    pub __account__: &'entrypoint mut Box<Account<'info, Items>>,
-    pub __programs__: &'entrypoint ProgramsMap<'info>,
-    pub owner: Pubkey,
-    pub depot: Pubkey,
-    pub name_array: Mutable<[u16; 32]>,
-    pub info_array: Mutable<[u16; 256]>,
-    pub coordinates_class: Mutable<Coordinates>,
-    pub cur_time: i64,
-    pub time_created: i64,
-}
+        let expiry_time = loaded.expiry_time;
 
-pub fn init_depot_handler<'info>(
-    mut clock: Sysvar<'info, Clock>,
-    mut payer: SeahorseSigner<'info, '_>,
-    mut owner: SeahorseSigner<'info, '_>,
-    mut depot: Empty<Mutable<LoadedDepot<'info, '_>>>,
-    mut name_array: [u16; 32],
-    mut info_array: [u16; 256],
-    mut coordinates_class: Coordinates,
-    mut seed_random: u128,
-) -> () {
-    let mut time = clock.unix_timestamp;
-    let mut depot = depot.account.clone();
+        loaded.__account__.expiry_time = expiry_time;
 
-    assign!(depot.borrow_mut().owner, owner.key());
+        let has_environment_range = loaded.has_environment_range;
 
-    assign!(depot.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+        loaded.__account__.has_environment_range = has_environment_range;
 
-    assign!(depot.borrow_mut().info_array, Mutable::<[u16; 256]>::new(info_array));
+        let environment_range_class = loaded.environment_range_class.borrow().clone();
 
-    assign!(depot.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+        loaded.__account__.environment_range_class = environment_range_class;
 
-    assign!(depot.borrow_mut().time_created, time);
-}
+        let environment_log_class = loaded.environment_log_class.borrow().clone();
 
-pub fn init_items_handler<'info>(
-    mut clock: Sysvar<'info, Clock>,
-    mut payer: SeahorseSigner<'info, '_>,
-    mut owner: SeahorseSigner<'info, '_>,
-    mut depot_signer: SeahorseSigner<'info, '_>,
-    mut depot: Mutable<LoadedDepot<'info, '_>>,
-    mut items: Empty<Mutable<LoadedItems<'info, '_>>>,
-    mut name_array: [u16; 32],
-    mut info_array: [u16; 256],
-    mut coordinates_class: Coordinates,
-    mut seed_random: u128,
-) -> () {
-    let mut time = clock.unix_timestamp;
+        loaded.__account__.environment_log_class = environment_log_class;
 
-    if !(depot.borrow().owner == depot_signer.key()) {
-        panic!("Depot signer is not the owner");
-    }
+        let non_compliant = loaded.non_compliant;
 
-    let mut items = items.account.clone();
+        loaded.__account__.non_compliant = non_compliant;
 
-    assign!(items.borrow_mut().owner, owner.key());
+        let last_updated_by = loaded.last_updated_by.clone();
 
-    assign!(items.borrow_mut().depot, depot.borrow().__account__.key());
+        loaded.__account__.last_updated_by = last_updated_by;
 
-    assign!(items.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+        let tag_pubkey = loaded.tag_pubkey.clone();
 
-    assign!(items.borrow_mut().info_array, Mutable::<[u16; 256]>::new(info_array));
+        loaded.__account__.tag_pubkey = tag_pubkey;
 
-    assign!(items.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+        let tag_scan_count = loaded.tag_scan_count;
 
-    assign!(items.borrow_mut().cur_time, time);
+        loaded.__account__.tag_scan_count = tag_scan_count;
 
-    assign!(items.borrow_mut().time_created, time);
-}
+        let last_scan_time = loaded.last_scan_time;
 
-pub fn transfer_items_handler<'info>(
-    mut clock: Sysvar<'info, Clock>,
-    mut payer: SeahorseSigner<'info, '_>,
-    mut old_depot_owner: SeahorseSigner<'info, '_>,
-    mut new_depot_owner: SeahorseSigner<'info, '_>,
-    mut old_depot: Mutable<LoadedDepot<'info, '_>>,
-    mut new_depot: Mutable<LoadedDepot<'info, '_>>,
-    mut items: Mutable<LoadedItems<'info, '_>>,
-) -> () {
-    let mut time = clock.unix_timestamp;
+        loaded.__account__.last_scan_time = last_scan_time;
 
-    if !(old_depot.borrow().owner == old_depot_owner.key()) {
-        panic!("Old depot owner is not the owner of old depot");
-    }
+        let last_scan_coordinates_class = loaded.last_scan_coordinates_class.borrow().clone();
 
-    if !(new_depot.borrow().owner == new_depot_owner.key()) {
-        panic!("New depot owner is not the owner of new depot");
-    }
+        loaded.__account__.last_scan_coordinates_class = last_scan_coordinates_class;
 
-    if !(items.borrow().depot == old_depot.borrow().__account__.key()) {
-        panic!("Old depot is not same as items depot");
-    }
+        let last_transfer_time = loaded.last_transfer_time;
 
-    if !(old_depot.borrow().__account__.key() != new_depot.borrow().__account__.key()) {
-        panic!("Old depot is same as new depot");
-    }
+        loaded.__account__.last_transfer_time = last_transfer_time;
 
-    assign!(
-        items.borrow_mut().depot,
-        new_depot.borrow().__account__.key()
-    );
+        let require_verified_destination = loaded.require_verified_destination;
 
-    assign!(items.borrow_mut().cur_time, time);
-}
+        loaded.__account__.require_verified_destination = require_verified_destination;
 
-pub fn update_items_handler<'info>(
-    mut clock: Sysvar<'info, Clock>,
-    mut payer: SeahorseSigner<'info, '_>,
-    mut owner: SeahorseSigner<'info, '_>,
-    mut items: Mutable<LoadedItems<'info, '_>>,
-    mut coordinates_class: Coordinates,
-) -> () {
-    let mut time = clock.unix_timestamp;
+        let frozen = loaded.frozen;
 
-    if !(items.borrow().owner == owner.key()) {
-        panic!("Owner is not the owner");
-    }
+        loaded.__account__.frozen = frozen;
 
-    assign!(items.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+        let freeze_reason = loaded.freeze_reason;
 
-    assign!(items.borrow_mut().cur_time, time);
+        loaded.__account__.freeze_reason = freeze_reason;
+
+        let frozen_by = loaded.frozen_by.clone();
+
+        loaded.__account__.frozen_by = frozen_by;
+
+        let frozen_at = loaded.frozen_at;
+
+        loaded.__account__.frozen_at = frozen_at;
+
+        let freeze_record_count = loaded.freeze_record_count;
+
+        loaded.__account__.freeze_record_count = freeze_record_count;
+
+        let inspection_count = loaded.inspection_count;
+
+        loaded.__account__.inspection_count = inspection_count;
+
+        let last_inspection_verdict = loaded.last_inspection_verdict;
+
+        loaded.__account__.last_inspection_verdict = last_inspection_verdict;
+
+        let last_inspection_depot = loaded.last_inspection_depot.clone();
+
+        loaded.__account__.last_inspection_depot = last_inspection_depot;
+
+        let last_inspection_time = loaded.last_inspection_time;
+
+        loaded.__account__.last_inspection_time = last_inspection_time;
+
+        let origin_depot = loaded.origin_depot.clone();
+
+        loaded.__account__.origin_depot = origin_depot;
+
+        let transfer_count = loaded.transfer_count;
+
+        loaded.__account__.transfer_count = transfer_count;
+
+        let document_count = loaded.document_count;
+
+        loaded.__account__.document_count = document_count;
+
+        let pending_document_types = loaded.pending_document_types;
+
+        loaded.__account__.pending_document_types = pending_document_types;
+
+        let classification_class = loaded.classification_class.borrow().clone();
+
+        loaded.__account__.classification_class = classification_class;
+
+        let weight_grams = loaded.weight_grams;
+
+        loaded.__account__.weight_grams = weight_grams;
+
+        let volume_cm3 = loaded.volume_cm3;
+
+        loaded.__account__.volume_cm3 = volume_cm3;
+
+        let sub_location_class = loaded.sub_location_class.borrow().clone();
+
+        loaded.__account__.sub_location_class = sub_location_class;
+
+        let sub_location_updated_by = loaded.sub_location_updated_by.clone();
+
+        loaded.__account__.sub_location_updated_by = sub_location_updated_by;
+
+        let sub_location_time = loaded.sub_location_time;
+
+        loaded.__account__.sub_location_time = sub_location_time;
+
+        let received_after_hours = loaded.received_after_hours;
+
+        loaded.__account__.received_after_hours = received_after_hours;
+
+        let carrier = loaded.carrier.clone();
+
+        loaded.__account__.carrier = carrier;
+
+        let carrier_pickup_depot = loaded.carrier_pickup_depot.clone();
+
+        loaded.__account__.carrier_pickup_depot = carrier_pickup_depot;
+
+        let carrier_pickup_time = loaded.carrier_pickup_time;
+
+        loaded.__account__.carrier_pickup_time = carrier_pickup_time;
+
+        let has_route_plan = loaded.has_route_plan;
+
+        loaded.__account__.has_route_plan = has_route_plan;
+
+        let destination_depot = loaded.destination_depot.clone();
+
+        loaded.__account__.destination_depot = destination_depot;
+
+        let delivery_deadline = loaded.delivery_deadline;
+
+        loaded.__account__.delivery_deadline = delivery_deadline;
+
+        let delivery_status = loaded.delivery_status;
+
+        loaded.__account__.delivery_status = delivery_status;
+
+        let arrival_time = loaded.arrival_time;
+
+        loaded.__account__.arrival_time = arrival_time;
+
+        let lateness_seconds = loaded.lateness_seconds;
+
+        loaded.__account__.lateness_seconds = lateness_seconds;
+
+        let overdue_marked_at = loaded.overdue_marked_at;
+
+        loaded.__account__.overdue_marked_at = overdue_marked_at;
+
+        let consignee = loaded.consignee.clone();
+
+        loaded.__account__.consignee = consignee;
+
+        let delivered = loaded.delivered;
+
+        loaded.__account__.delivered = delivered;
+
+        let delivered_at = loaded.delivered_at;
+
+        loaded.__account__.delivered_at = delivered_at;
+
+        let delivery_coordinates_class = loaded.delivery_coordinates_class.borrow().clone();
+
+        loaded.__account__.delivery_coordinates_class = delivery_coordinates_class;
+
+        let has_delivery_note = loaded.has_delivery_note;
+
+        loaded.__account__.has_delivery_note = has_delivery_note;
+
+        let delivery_note_hash = loaded.delivery_note_hash.borrow().clone();
+
+        loaded.__account__.delivery_note_hash = delivery_note_hash;
+
+        let compliance_override_count = loaded.compliance_override_count;
+
+        loaded.__account__.compliance_override_count = compliance_override_count;
+
+        let last_compliance_override_time = loaded.last_compliance_override_time;
+
+        loaded.__account__.last_compliance_override_time = last_compliance_override_time;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedItems<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Items>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub owner: Pubkey,
+    pub depot: Pubkey,
+    pub name_array: Mutable<[u16; 32]>,
+    pub info_array: Mutable<[u16; 256]>,
+    pub coordinates_class: Mutable<Coordinates>,
+    pub cur_time: i64,
+    pub time_created: i64,
+    pub expiry_time: i64,
+    pub has_environment_range: bool,
+    pub environment_range_class: Mutable<EnvironmentRange>,
+    pub environment_log_class: Mutable<EnvironmentLog>,
+    pub non_compliant: bool,
+    pub last_updated_by: Pubkey,
+    pub tag_pubkey: Pubkey,
+    pub tag_scan_count: u64,
+    pub last_scan_time: i64,
+    pub last_scan_coordinates_class: Mutable<Coordinates>,
+    pub last_transfer_time: i64,
+    pub require_verified_destination: bool,
+    pub frozen: bool,
+    pub freeze_reason: u16,
+    pub frozen_by: Pubkey,
+    pub frozen_at: i64,
+    pub freeze_record_count: u32,
+    pub inspection_count: u32,
+    pub last_inspection_verdict: InspectionVerdict,
+    pub last_inspection_depot: Pubkey,
+    pub last_inspection_time: i64,
+    pub origin_depot: Pubkey,
+    pub transfer_count: u32,
+    pub document_count: u32,
+    pub pending_document_types: u8,
+    pub classification_class: Mutable<GoodsClassification>,
+    pub weight_grams: u64,
+    pub volume_cm3: u64,
+    pub sub_location_class: Mutable<SubLocation>,
+    pub sub_location_updated_by: Pubkey,
+    pub sub_location_time: i64,
+    pub received_after_hours: bool,
+    pub carrier: Pubkey,
+    pub carrier_pickup_depot: Pubkey,
+    pub carrier_pickup_time: i64,
+    pub has_route_plan: bool,
+    pub destination_depot: Pubkey,
+    pub delivery_deadline: i64,
+    pub delivery_status: DeliveryStatus,
+    pub arrival_time: i64,
+    pub lateness_seconds: i64,
+    pub overdue_marked_at: i64,
+    pub consignee: Pubkey,
+    pub delivered: bool,
+    pub delivered_at: i64,
+    pub delivery_coordinates_class: Mutable<Coordinates>,
+    pub has_delivery_note: bool,
+    pub delivery_note_hash: Mutable<[u8; 32]>,
+    pub compliance_override_count: u32,
+    pub last_compliance_override_time: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct DeviceAuthorization {
+    pub items: Pubkey,
+    pub device: Pubkey,
+    pub authority: Pubkey,
+    pub expiry_time: i64,
+    pub time_created: i64,
+    pub last_update_time: i64,
+    pub depot: Pubkey,
+}
+
+impl<'info, 'entrypoint> DeviceAuthorization {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedDeviceAuthorization<'info, 'entrypoint>> {
+        let items = account.items.clone();
+        let device = account.device.clone();
+        let authority = account.authority.clone();
+        let expiry_time = account.expiry_time;
+        let time_created = account.time_created;
+        let last_update_time = account.last_update_time;
+        let depot = account.depot.clone();
+
+        Mutable::new(LoadedDeviceAuthorization {
+            __account__: account,
+            __programs__: programs_map,
+            items,
+            device,
+            authority,
+            expiry_time,
+            time_created,
+            last_update_time,
+            depot,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedDeviceAuthorization>) {
+        let mut loaded = loaded.borrow_mut();
+        let items = loaded.items.clone();
+
+        loaded.__account__.items = items;
+
+        let device = loaded.device.clone();
+
+        loaded.__account__.device = device;
+
+        let authority = loaded.authority.clone();
+
+        loaded.__account__.authority = authority;
+
+        let expiry_time = loaded.expiry_time;
+
+        loaded.__account__.expiry_time = expiry_time;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let last_update_time = loaded.last_update_time;
+
+        loaded.__account__.last_update_time = last_update_time;
+
+        let depot = loaded.depot.clone();
+
+        loaded.__account__.depot = depot;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedDeviceAuthorization<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, DeviceAuthorization>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub items: Pubkey,
+    pub device: Pubkey,
+    pub authority: Pubkey,
+    pub expiry_time: i64,
+    pub time_created: i64,
+    pub last_update_time: i64,
+    pub depot: Pubkey,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct DepotRole {
+    pub depot: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub granted_by: Pubkey,
+    pub time_created: i64,
+    pub owner_epoch: u32,
+}
+
+impl<'info, 'entrypoint> DepotRole {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedDepotRole<'info, 'entrypoint>> {
+        let depot = account.depot.clone();
+        let delegate = account.delegate.clone();
+        let permissions = account.permissions;
+        let granted_by = account.granted_by.clone();
+        let time_created = account.time_created;
+        let owner_epoch = account.owner_epoch;
+
+        Mutable::new(LoadedDepotRole {
+            __account__: account,
+            __programs__: programs_map,
+            depot,
+            delegate,
+            permissions,
+            granted_by,
+            time_created,
+            owner_epoch,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedDepotRole>) {
+        let mut loaded = loaded.borrow_mut();
+        let depot = loaded.depot.clone();
+
+        loaded.__account__.depot = depot;
+
+        let delegate = loaded.delegate.clone();
+
+        loaded.__account__.delegate = delegate;
+
+        let permissions = loaded.permissions;
+
+        loaded.__account__.permissions = permissions;
+
+        let granted_by = loaded.granted_by.clone();
+
+        loaded.__account__.granted_by = granted_by;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let owner_epoch = loaded.owner_epoch;
+
+        loaded.__account__.owner_epoch = owner_epoch;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedDepotRole<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, DepotRole>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub depot: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub granted_by: Pubkey,
+    pub time_created: i64,
+    pub owner_epoch: u32,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Multisig {
+    pub owners: [Pubkey; 10],
+    pub owner_count: u8,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub time_created: i64,
+    pub owners_epoch: u32,
+}
+
+impl<'info, 'entrypoint> Multisig {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedMultisig<'info, 'entrypoint>> {
+        let owners = Mutable::new(account.owners.clone());
+        let owner_count = account.owner_count;
+        let threshold = account.threshold;
+        let proposal_count = account.proposal_count;
+        let time_created = account.time_created;
+        let owners_epoch = account.owners_epoch;
+
+        Mutable::new(LoadedMultisig {
+            __account__: account,
+            __programs__: programs_map,
+            owners,
+            owner_count,
+            threshold,
+            proposal_count,
+            time_created,
+            owners_epoch,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedMultisig>) {
+        let mut loaded = loaded.borrow_mut();
+        let owners = loaded.owners.borrow().clone();
+
+        loaded.__account__.owners = owners;
+
+        let owner_count = loaded.owner_count;
+
+        loaded.__account__.owner_count = owner_count;
+
+        let threshold = loaded.threshold;
+
+        loaded.__account__.threshold = threshold;
+
+        let proposal_count = loaded.proposal_count;
+
+        loaded.__account__.proposal_count = proposal_count;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let owners_epoch = loaded.owners_epoch;
+
+        loaded.__account__.owners_epoch = owners_epoch;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedMultisig<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Multisig>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub owners: Mutable<[Pubkey; 10]>,
+    pub owner_count: u8,
+    pub threshold: u8,
+    pub proposal_count: u64,
+    pub time_created: i64,
+    pub owners_epoch: u32,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub depot: Pubkey,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub approvals: [bool; 10],
+    pub approval_count: u8,
+    pub executed: bool,
+    pub time_created: i64,
+    pub time_executed: i64,
+    pub depot_owner_epoch: u32,
+    pub multisig_owners_epoch: u32,
+}
+
+impl<'info, 'entrypoint> MultisigProposal {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedMultisigProposal<'info, 'entrypoint>> {
+        let multisig = account.multisig.clone();
+        let depot = account.depot.clone();
+        let proposer = account.proposer.clone();
+        let action = account.action.clone();
+        let approvals = Mutable::new(account.approvals.clone());
+        let approval_count = account.approval_count;
+        let executed = account.executed;
+        let time_created = account.time_created;
+        let time_executed = account.time_executed;
+        let depot_owner_epoch = account.depot_owner_epoch;
+        let multisig_owners_epoch = account.multisig_owners_epoch;
+
+        Mutable::new(LoadedMultisigProposal {
+            __account__: account,
+            __programs__: programs_map,
+            multisig,
+            depot,
+            proposer,
+            action,
+            approvals,
+            approval_count,
+            executed,
+            time_created,
+            time_executed,
+            depot_owner_epoch,
+            multisig_owners_epoch,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedMultisigProposal>) {
+        let mut loaded = loaded.borrow_mut();
+        let multisig = loaded.multisig.clone();
+
+        loaded.__account__.multisig = multisig;
+
+        let depot = loaded.depot.clone();
+
+        loaded.__account__.depot = depot;
+
+        let proposer = loaded.proposer.clone();
+
+        loaded.__account__.proposer = proposer;
+
+        let action = loaded.action.clone();
+
+        loaded.__account__.action = action;
+
+        let approvals = loaded.approvals.borrow().clone();
+
+        loaded.__account__.approvals = approvals;
+
+        let approval_count = loaded.approval_count;
+
+        loaded.__account__.approval_count = approval_count;
+
+        let executed = loaded.executed;
+
+        loaded.__account__.executed = executed;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let time_executed = loaded.time_executed;
+
+        loaded.__account__.time_executed = time_executed;
+
+        let depot_owner_epoch = loaded.depot_owner_epoch;
+
+        loaded.__account__.depot_owner_epoch = depot_owner_epoch;
+
+        let multisig_owners_epoch = loaded.multisig_owners_epoch;
+
+        loaded.__account__.multisig_owners_epoch = multisig_owners_epoch;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedMultisigProposal<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, MultisigProposal>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub multisig: Pubkey,
+    pub depot: Pubkey,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub approvals: Mutable<[bool; 10]>,
+    pub approval_count: u8,
+    pub executed: bool,
+    pub time_created: i64,
+    pub time_executed: i64,
+    pub depot_owner_epoch: u32,
+    pub multisig_owners_epoch: u32,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Organization {
+    pub name_array: [u16; 32],
+    pub admins: [Pubkey; 8],
+    pub admin_count: u8,
+    pub depot_count: u32,
+    pub single_signer_internal_transfers: bool,
+    pub time_created: i64,
+    pub require_verified_destination: bool,
+}
+
+impl<'info, 'entrypoint> Organization {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedOrganization<'info, 'entrypoint>> {
+        let name_array = Mutable::new(account.name_array.clone());
+        let admins = Mutable::new(account.admins.clone());
+        let admin_count = account.admin_count;
+        let depot_count = account.depot_count;
+        let single_signer_internal_transfers = account.single_signer_internal_transfers;
+        let time_created = account.time_created;
+        let require_verified_destination = account.require_verified_destination;
+
+        Mutable::new(LoadedOrganization {
+            __account__: account,
+            __programs__: programs_map,
+            name_array,
+            admins,
+            admin_count,
+            depot_count,
+            single_signer_internal_transfers,
+            time_created,
+            require_verified_destination,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedOrganization>) {
+        let mut loaded = loaded.borrow_mut();
+        let name_array = loaded.name_array.borrow().clone();
+
+        loaded.__account__.name_array = name_array;
+
+        let admins = loaded.admins.borrow().clone();
+
+        loaded.__account__.admins = admins;
+
+        let admin_count = loaded.admin_count;
+
+        loaded.__account__.admin_count = admin_count;
+
+        let depot_count = loaded.depot_count;
+
+        loaded.__account__.depot_count = depot_count;
+
+        let single_signer_internal_transfers = loaded.single_signer_internal_transfers;
+
+        loaded.__account__.single_signer_internal_transfers = single_signer_internal_transfers;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let require_verified_destination = loaded.require_verified_destination;
+
+        loaded.__account__.require_verified_destination = require_verified_destination;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedOrganization<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Organization>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub name_array: Mutable<[u16; 32]>,
+    pub admins: Mutable<[Pubkey; 8]>,
+    pub admin_count: u8,
+    pub depot_count: u32,
+    pub single_signer_internal_transfers: bool,
+    pub time_created: i64,
+    pub require_verified_destination: bool,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct OrganizationDepot {
+    pub organization: Pubkey,
+    pub depot: Pubkey,
+    pub index: u32,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> OrganizationDepot {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedOrganizationDepot<'info, 'entrypoint>> {
+        let organization = account.organization.clone();
+        let depot = account.depot.clone();
+        let index = account.index;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedOrganizationDepot {
+            __account__: account,
+            __programs__: programs_map,
+            organization,
+            depot,
+            index,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedOrganizationDepot>) {
+        let mut loaded = loaded.borrow_mut();
+        let organization = loaded.organization.clone();
+
+        loaded.__account__.organization = organization;
+
+        let depot = loaded.depot.clone();
+
+        loaded.__account__.depot = depot;
+
+        let index = loaded.index;
+
+        loaded.__account__.index = index;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedOrganizationDepot<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, OrganizationDepot>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub organization: Pubkey,
+    pub depot: Pubkey,
+    pub index: u32,
+    pub time_created: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub paused: bool,
+    pub max_speed: f64,
+    pub depot_fee: u64,
+    pub items_fee: u64,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> Config {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedConfig<'info, 'entrypoint>> {
+        let admin = account.admin.clone();
+        let pending_admin = account.pending_admin.clone();
+        let paused = account.paused;
+        let max_speed = account.max_speed;
+        let depot_fee = account.depot_fee;
+        let items_fee = account.items_fee;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedConfig {
+            __account__: account,
+            __programs__: programs_map,
+            admin,
+            pending_admin,
+            paused,
+            max_speed,
+            depot_fee,
+            items_fee,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedConfig>) {
+        let mut loaded = loaded.borrow_mut();
+        let admin = loaded.admin.clone();
+
+        loaded.__account__.admin = admin;
+
+        let pending_admin = loaded.pending_admin.clone();
+
+        loaded.__account__.pending_admin = pending_admin;
+
+        let paused = loaded.paused;
+
+        loaded.__account__.paused = paused;
+
+        let max_speed = loaded.max_speed;
+
+        loaded.__account__.max_speed = max_speed;
+
+        let depot_fee = loaded.depot_fee;
+
+        loaded.__account__.depot_fee = depot_fee;
+
+        let items_fee = loaded.items_fee;
+
+        loaded.__account__.items_fee = items_fee;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedConfig<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Config>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub paused: bool,
+    pub max_speed: f64,
+    pub depot_fee: u64,
+    pub items_fee: u64,
+    pub time_created: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct AccreditationAuthority {
+    pub authority: Pubkey,
+    pub name_array: [u16; 32],
+    pub active: bool,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> AccreditationAuthority {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedAccreditationAuthority<'info, 'entrypoint>> {
+        let authority = account.authority.clone();
+        let name_array = Mutable::new(account.name_array.clone());
+        let active = account.active;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedAccreditationAuthority {
+            __account__: account,
+            __programs__: programs_map,
+            authority,
+            name_array,
+            active,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedAccreditationAuthority>) {
+        let mut loaded = loaded.borrow_mut();
+        let authority = loaded.authority.clone();
+
+        loaded.__account__.authority = authority;
+
+        let name_array = loaded.name_array.borrow().clone();
+
+        loaded.__account__.name_array = name_array;
+
+        let active = loaded.active;
+
+        loaded.__account__.active = active;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedAccreditationAuthority<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, AccreditationAuthority>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub authority: Pubkey,
+    pub name_array: Mutable<[u16; 32]>,
+    pub active: bool,
+    pub time_created: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Regulator {
+    pub regulator: Pubkey,
+    pub name_array: [u16; 32],
+    pub active: bool,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> Regulator {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedRegulator<'info, 'entrypoint>> {
+        let regulator = account.regulator.clone();
+        let name_array = Mutable::new(account.name_array.clone());
+        let active = account.active;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedRegulator {
+            __account__: account,
+            __programs__: programs_map,
+            regulator,
+            name_array,
+            active,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedRegulator>) {
+        let mut loaded = loaded.borrow_mut();
+        let regulator = loaded.regulator.clone();
+
+        loaded.__account__.regulator = regulator;
+
+        let name_array = loaded.name_array.borrow().clone();
+
+        loaded.__account__.name_array = name_array;
+
+        let active = loaded.active;
+
+        loaded.__account__.active = active;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedRegulator<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Regulator>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub regulator: Pubkey,
+    pub name_array: Mutable<[u16; 32]>,
+    pub active: bool,
+    pub time_created: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct FreezeRecord {
+    pub items: Pubkey,
+    pub regulator: Pubkey,
+    pub frozen: bool,
+    pub reason_code: u16,
+    pub time: i64,
+}
+
+impl<'info, 'entrypoint> FreezeRecord {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedFreezeRecord<'info, 'entrypoint>> {
+        let items = account.items.clone();
+        let regulator = account.regulator.clone();
+        let frozen = account.frozen;
+        let reason_code = account.reason_code;
+        let time = account.time;
+
+        Mutable::new(LoadedFreezeRecord {
+            __account__: account,
+            __programs__: programs_map,
+            items,
+            regulator,
+            frozen,
+            reason_code,
+            time,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedFreezeRecord>) {
+        let mut loaded = loaded.borrow_mut();
+        let items = loaded.items.clone();
+
+        loaded.__account__.items = items;
+
+        let regulator = loaded.regulator.clone();
+
+        loaded.__account__.regulator = regulator;
+
+        let frozen = loaded.frozen;
+
+        loaded.__account__.frozen = frozen;
+
+        let reason_code = loaded.reason_code;
+
+        loaded.__account__.reason_code = reason_code;
+
+        let time = loaded.time;
+
+        loaded.__account__.time = time;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedFreezeRecord<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, FreezeRecord>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub items: Pubkey,
+    pub regulator: Pubkey,
+    pub frozen: bool,
+    pub reason_code: u16,
+    pub time: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Inspector {
+    pub inspector: Pubkey,
+    pub name_array: [u16; 32],
+    pub active: bool,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> Inspector {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedInspector<'info, 'entrypoint>> {
+        let inspector = account.inspector.clone();
+        let name_array = Mutable::new(account.name_array.clone());
+        let active = account.active;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedInspector {
+            __account__: account,
+            __programs__: programs_map,
+            inspector,
+            name_array,
+            active,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedInspector>) {
+        let mut loaded = loaded.borrow_mut();
+        let inspector = loaded.inspector.clone();
+
+        loaded.__account__.inspector = inspector;
+
+        let name_array = loaded.name_array.borrow().clone();
+
+        loaded.__account__.name_array = name_array;
+
+        let active = loaded.active;
+
+        loaded.__account__.active = active;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedInspector<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Inspector>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub inspector: Pubkey,
+    pub name_array: Mutable<[u16; 32]>,
+    pub active: bool,
+    pub time_created: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Inspection {
+    pub items: Pubkey,
+    pub depot: Pubkey,
+    pub inspector: Pubkey,
+    pub verdict: InspectionVerdict,
+    pub findings_hash: [u8; 32],
+    pub time: i64,
+}
+
+impl<'info, 'entrypoint> Inspection {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedInspection<'info, 'entrypoint>> {
+        let items = account.items.clone();
+        let depot = account.depot.clone();
+        let inspector = account.inspector.clone();
+        let verdict = account.verdict;
+        let findings_hash = Mutable::new(account.findings_hash.clone());
+        let time = account.time;
+
+        Mutable::new(LoadedInspection {
+            __account__: account,
+            __programs__: programs_map,
+            items,
+            depot,
+            inspector,
+            verdict,
+            findings_hash,
+            time,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedInspection>) {
+        let mut loaded = loaded.borrow_mut();
+        let items = loaded.items.clone();
+
+        loaded.__account__.items = items;
+
+        let depot = loaded.depot.clone();
+
+        loaded.__account__.depot = depot;
+
+        let inspector = loaded.inspector.clone();
+
+        loaded.__account__.inspector = inspector;
+
+        let verdict = loaded.verdict;
+
+        loaded.__account__.verdict = verdict;
+
+        let findings_hash = loaded.findings_hash.borrow().clone();
+
+        loaded.__account__.findings_hash = findings_hash;
+
+        let time = loaded.time;
+
+        loaded.__account__.time = time;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedInspection<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Inspection>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub items: Pubkey,
+    pub depot: Pubkey,
+    pub inspector: Pubkey,
+    pub verdict: InspectionVerdict,
+    pub findings_hash: Mutable<[u8; 32]>,
+    pub time: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Certifier {
+    pub certifier: Pubkey,
+    pub name_array: [u16; 32],
+    pub active: bool,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> Certifier {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedCertifier<'info, 'entrypoint>> {
+        let certifier = account.certifier.clone();
+        let name_array = Mutable::new(account.name_array.clone());
+        let active = account.active;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedCertifier {
+            __account__: account,
+            __programs__: programs_map,
+            certifier,
+            name_array,
+            active,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedCertifier>) {
+        let mut loaded = loaded.borrow_mut();
+        let certifier = loaded.certifier.clone();
+
+        loaded.__account__.certifier = certifier;
+
+        let name_array = loaded.name_array.borrow().clone();
+
+        loaded.__account__.name_array = name_array;
+
+        let active = loaded.active;
+
+        loaded.__account__.active = active;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedCertifier<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Certifier>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub certifier: Pubkey,
+    pub name_array: Mutable<[u16; 32]>,
+    pub active: bool,
+    pub time_created: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Certification {
+    pub subject: Pubkey,
+    pub subject_is_depot: bool,
+    pub certifier: Pubkey,
+    pub scheme: [u8; 16],
+    pub valid_from: i64,
+    pub valid_until: i64,
+    pub revoked: bool,
+    pub revoked_at: i64,
+    pub time_created: i64,
+    pub renewed_at: i64,
+}
+
+impl<'info, 'entrypoint> Certification {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedCertification<'info, 'entrypoint>> {
+        let subject = account.subject.clone();
+        let subject_is_depot = account.subject_is_depot;
+        let certifier = account.certifier.clone();
+        let scheme = Mutable::new(account.scheme.clone());
+        let valid_from = account.valid_from;
+        let valid_until = account.valid_until;
+        let revoked = account.revoked;
+        let revoked_at = account.revoked_at;
+        let time_created = account.time_created;
+        let renewed_at = account.renewed_at;
+
+        Mutable::new(LoadedCertification {
+            __account__: account,
+            __programs__: programs_map,
+            subject,
+            subject_is_depot,
+            certifier,
+            scheme,
+            valid_from,
+            valid_until,
+            revoked,
+            revoked_at,
+            time_created,
+            renewed_at,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedCertification>) {
+        let mut loaded = loaded.borrow_mut();
+        let subject = loaded.subject.clone();
+
+        loaded.__account__.subject = subject;
+
+        let subject_is_depot = loaded.subject_is_depot;
+
+        loaded.__account__.subject_is_depot = subject_is_depot;
+
+        let certifier = loaded.certifier.clone();
+
+        loaded.__account__.certifier = certifier;
+
+        let scheme = loaded.scheme.borrow().clone();
+
+        loaded.__account__.scheme = scheme;
+
+        let valid_from = loaded.valid_from;
+
+        loaded.__account__.valid_from = valid_from;
+
+        let valid_until = loaded.valid_until;
+
+        loaded.__account__.valid_until = valid_until;
+
+        let revoked = loaded.revoked;
+
+        loaded.__account__.revoked = revoked;
+
+        let revoked_at = loaded.revoked_at;
+
+        loaded.__account__.revoked_at = revoked_at;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let renewed_at = loaded.renewed_at;
+
+        loaded.__account__.renewed_at = renewed_at;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedCertification<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Certification>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub subject: Pubkey,
+    pub subject_is_depot: bool,
+    pub certifier: Pubkey,
+    pub scheme: Mutable<[u8; 16]>,
+    pub valid_from: i64,
+    pub valid_until: i64,
+    pub revoked: bool,
+    pub revoked_at: i64,
+    pub time_created: i64,
+    pub renewed_at: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Document {
+    pub items: Pubkey,
+    pub document_type: DocumentType,
+    pub hash: [u8; 32],
+    pub has_uri: bool,
+    pub uri_array: [u16; 128],
+    pub signer: Pubkey,
+    pub for_transfer: bool,
+    pub transfer_index: u32,
+    pub time: i64,
+}
+
+impl<'info, 'entrypoint> Document {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedDocument<'info, 'entrypoint>> {
+        let items = account.items.clone();
+        let document_type = account.document_type;
+        let hash = Mutable::new(account.hash.clone());
+        let has_uri = account.has_uri;
+        let uri_array = Mutable::new(account.uri_array.clone());
+        let signer = account.signer.clone();
+        let for_transfer = account.for_transfer;
+        let transfer_index = account.transfer_index;
+        let time = account.time;
+
+        Mutable::new(LoadedDocument {
+            __account__: account,
+            __programs__: programs_map,
+            items,
+            document_type,
+            hash,
+            has_uri,
+            uri_array,
+            signer,
+            for_transfer,
+            transfer_index,
+            time,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedDocument>) {
+        let mut loaded = loaded.borrow_mut();
+        let items = loaded.items.clone();
+
+        loaded.__account__.items = items;
+
+        let document_type = loaded.document_type;
+
+        loaded.__account__.document_type = document_type;
+
+        let hash = loaded.hash.borrow().clone();
+
+        loaded.__account__.hash = hash;
+
+        let has_uri = loaded.has_uri;
+
+        loaded.__account__.has_uri = has_uri;
+
+        let uri_array = loaded.uri_array.borrow().clone();
+
+        loaded.__account__.uri_array = uri_array;
+
+        let signer = loaded.signer.clone();
+
+        loaded.__account__.signer = signer;
+
+        let for_transfer = loaded.for_transfer;
+
+        loaded.__account__.for_transfer = for_transfer;
+
+        let transfer_index = loaded.transfer_index;
+
+        loaded.__account__.transfer_index = transfer_index;
+
+        let time = loaded.time;
+
+        loaded.__account__.time = time;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedDocument<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Document>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub items: Pubkey,
+    pub document_type: DocumentType,
+    pub hash: Mutable<[u8; 32]>,
+    pub has_uri: bool,
+    pub uri_array: Mutable<[u16; 128]>,
+    pub signer: Pubkey,
+    pub for_transfer: bool,
+    pub transfer_index: u32,
+    pub time: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct TransferRules {
+    pub rules: [TransferRule; 16],
+    pub rule_count: u8,
+    pub time_created: i64,
+    pub last_update_time: i64,
+}
+
+impl<'info, 'entrypoint> TransferRules {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedTransferRules<'info, 'entrypoint>> {
+        let rules = Mutable::new(account.rules.clone());
+        let rule_count = account.rule_count;
+        let time_created = account.time_created;
+        let last_update_time = account.last_update_time;
+
+        Mutable::new(LoadedTransferRules {
+            __account__: account,
+            __programs__: programs_map,
+            rules,
+            rule_count,
+            time_created,
+            last_update_time,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedTransferRules>) {
+        let mut loaded = loaded.borrow_mut();
+        let rules = loaded.rules.borrow().clone();
+
+        loaded.__account__.rules = rules;
+
+        let rule_count = loaded.rule_count;
+
+        loaded.__account__.rule_count = rule_count;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let last_update_time = loaded.last_update_time;
+
+        loaded.__account__.last_update_time = last_update_time;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedTransferRules<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, TransferRules>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub rules: Mutable<[TransferRule; 16]>,
+    pub rule_count: u8,
+    pub time_created: i64,
+    pub last_update_time: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct StocktakeRecord {
+    pub depot: Pubkey,
+    pub auditor: Pubkey,
+    pub counted_item_count: u64,
+    pub recorded_item_count: u64,
+    pub discrepancy: i64,
+    pub commitment: [u8; 32],
+    pub time: i64,
+}
+
+impl<'info, 'entrypoint> StocktakeRecord {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedStocktakeRecord<'info, 'entrypoint>> {
+        let depot = account.depot.clone();
+        let auditor = account.auditor.clone();
+        let counted_item_count = account.counted_item_count;
+        let recorded_item_count = account.recorded_item_count;
+        let discrepancy = account.discrepancy;
+        let commitment = Mutable::new(account.commitment.clone());
+        let time = account.time;
+
+        Mutable::new(LoadedStocktakeRecord {
+            __account__: account,
+            __programs__: programs_map,
+            depot,
+            auditor,
+            counted_item_count,
+            recorded_item_count,
+            discrepancy,
+            commitment,
+            time,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedStocktakeRecord>) {
+        let mut loaded = loaded.borrow_mut();
+        let depot = loaded.depot.clone();
+
+        loaded.__account__.depot = depot;
+
+        let auditor = loaded.auditor.clone();
+
+        loaded.__account__.auditor = auditor;
+
+        let counted_item_count = loaded.counted_item_count;
+
+        loaded.__account__.counted_item_count = counted_item_count;
+
+        let recorded_item_count = loaded.recorded_item_count;
+
+        loaded.__account__.recorded_item_count = recorded_item_count;
+
+        let discrepancy = loaded.discrepancy;
+
+        loaded.__account__.discrepancy = discrepancy;
+
+        let commitment = loaded.commitment.borrow().clone();
+
+        loaded.__account__.commitment = commitment;
+
+        let time = loaded.time;
+
+        loaded.__account__.time = time;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedStocktakeRecord<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, StocktakeRecord>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub depot: Pubkey,
+    pub auditor: Pubkey,
+    pub counted_item_count: u64,
+    pub recorded_item_count: u64,
+    pub discrepancy: i64,
+    pub commitment: Mutable<[u8; 32]>,
+    pub time: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct PartnerAgreement {
+    pub depot_a: Pubkey,
+    pub depot_b: Pubkey,
+    // Bitset of HS chapters 0-99; all zero allows every chapter
+    pub allowed_hs_chapters: [u8; 13],
+    // 0 allows any number of transfers per day
+    pub max_transfers_per_day: u32,
+    pub transfer_day: i64,
+    pub transfers_today: u32,
+    pub expiry_time: i64,
+    pub time_created: i64,
+    pub depot_a_owner_epoch: u32,
+    pub depot_b_owner_epoch: u32,
+}
+
+impl<'info, 'entrypoint> PartnerAgreement {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedPartnerAgreement<'info, 'entrypoint>> {
+        let depot_a = account.depot_a.clone();
+        let depot_b = account.depot_b.clone();
+        let allowed_hs_chapters = Mutable::new(account.allowed_hs_chapters.clone());
+        let max_transfers_per_day = account.max_transfers_per_day;
+        let transfer_day = account.transfer_day;
+        let transfers_today = account.transfers_today;
+        let expiry_time = account.expiry_time;
+        let time_created = account.time_created;
+        let depot_a_owner_epoch = account.depot_a_owner_epoch;
+        let depot_b_owner_epoch = account.depot_b_owner_epoch;
+
+        Mutable::new(LoadedPartnerAgreement {
+            __account__: account,
+            __programs__: programs_map,
+            depot_a,
+            depot_b,
+            allowed_hs_chapters,
+            max_transfers_per_day,
+            transfer_day,
+            transfers_today,
+            expiry_time,
+            time_created,
+            depot_a_owner_epoch,
+            depot_b_owner_epoch,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedPartnerAgreement>) {
+        let mut loaded = loaded.borrow_mut();
+        let depot_a = loaded.depot_a.clone();
+
+        loaded.__account__.depot_a = depot_a;
+
+        let depot_b = loaded.depot_b.clone();
+
+        loaded.__account__.depot_b = depot_b;
+
+        let allowed_hs_chapters = loaded.allowed_hs_chapters.borrow().clone();
+
+        loaded.__account__.allowed_hs_chapters = allowed_hs_chapters;
+
+        let max_transfers_per_day = loaded.max_transfers_per_day;
+
+        loaded.__account__.max_transfers_per_day = max_transfers_per_day;
+
+        let transfer_day = loaded.transfer_day;
+
+        loaded.__account__.transfer_day = transfer_day;
+
+        let transfers_today = loaded.transfers_today;
+
+        loaded.__account__.transfers_today = transfers_today;
+
+        let expiry_time = loaded.expiry_time;
+
+        loaded.__account__.expiry_time = expiry_time;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let depot_a_owner_epoch = loaded.depot_a_owner_epoch;
+
+        loaded.__account__.depot_a_owner_epoch = depot_a_owner_epoch;
+
+        let depot_b_owner_epoch = loaded.depot_b_owner_epoch;
+
+        loaded.__account__.depot_b_owner_epoch = depot_b_owner_epoch;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedPartnerAgreement<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, PartnerAgreement>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub depot_a: Pubkey,
+    pub depot_b: Pubkey,
+    pub allowed_hs_chapters: Mutable<[u8; 13]>,
+    pub max_transfers_per_day: u32,
+    pub transfer_day: i64,
+    pub transfers_today: u32,
+    pub expiry_time: i64,
+    pub time_created: i64,
+    pub depot_a_owner_epoch: u32,
+    pub depot_b_owner_epoch: u32,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct Carrier {
+    pub owner: Pubkey,
+    pub name_array: [u16; 32],
+    pub device_count: u32,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> Carrier {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedCarrier<'info, 'entrypoint>> {
+        let owner = account.owner.clone();
+        let name_array = Mutable::new(account.name_array.clone());
+        let device_count = account.device_count;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedCarrier {
+            __account__: account,
+            __programs__: programs_map,
+            owner,
+            name_array,
+            device_count,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedCarrier>) {
+        let mut loaded = loaded.borrow_mut();
+        let owner = loaded.owner.clone();
+
+        loaded.__account__.owner = owner;
+
+        let name_array = loaded.name_array.borrow().clone();
+
+        loaded.__account__.name_array = name_array;
+
+        let device_count = loaded.device_count;
+
+        loaded.__account__.device_count = device_count;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedCarrier<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, Carrier>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub owner: Pubkey,
+    pub name_array: Mutable<[u16; 32]>,
+    pub device_count: u32,
+    pub time_created: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct CarrierDevice {
+    pub carrier: Pubkey,
+    pub device: Pubkey,
+    pub time_created: i64,
+    pub last_update_time: i64,
+}
+
+impl<'info, 'entrypoint> CarrierDevice {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedCarrierDevice<'info, 'entrypoint>> {
+        let carrier = account.carrier.clone();
+        let device = account.device.clone();
+        let time_created = account.time_created;
+        let last_update_time = account.last_update_time;
+
+        Mutable::new(LoadedCarrierDevice {
+            __account__: account,
+            __programs__: programs_map,
+            carrier,
+            device,
+            time_created,
+            last_update_time,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedCarrierDevice>) {
+        let mut loaded = loaded.borrow_mut();
+        let carrier = loaded.carrier.clone();
+
+        loaded.__account__.carrier = carrier;
+
+        let device = loaded.device.clone();
+
+        loaded.__account__.device = device;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+
+        let last_update_time = loaded.last_update_time;
+
+        loaded.__account__.last_update_time = last_update_time;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedCarrierDevice<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, CarrierDevice>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub carrier: Pubkey,
+    pub device: Pubkey,
+    pub time_created: i64,
+    pub last_update_time: i64,
+}
+
+#[account]
+#[derive(Debug)]
+pub struct RoutePlan {
+    pub items: Pubkey,
+    pub waypoints: [Coordinates; 16],
+    pub waypoint_count: u8,
+    pub corridor_width_meters: f64,
+    pub deviating: bool,
+    pub deviation_count: u64,
+    pub last_deviation_time: i64,
+    pub last_deviation_meters: f64,
+    pub time_created: i64,
+}
+
+impl<'info, 'entrypoint> RoutePlan {
+    pub fn load(
+        account: &'entrypoint mut Box<Account<'info, Self>>,
+        programs_map: &'entrypoint ProgramsMap<'info>,
+    ) -> Mutable<LoadedRoutePlan<'info, 'entrypoint>> {
+        let items = account.items.clone();
+        let waypoints = Mutable::new(account.waypoints.clone());
+        let waypoint_count = account.waypoint_count;
+        let corridor_width_meters = account.corridor_width_meters;
+        let deviating = account.deviating;
+        let deviation_count = account.deviation_count;
+        let last_deviation_time = account.last_deviation_time;
+        let last_deviation_meters = account.last_deviation_meters;
+        let time_created = account.time_created;
+
+        Mutable::new(LoadedRoutePlan {
+            __account__: account,
+            __programs__: programs_map,
+            items,
+            waypoints,
+            waypoint_count,
+            corridor_width_meters,
+            deviating,
+            deviation_count,
+            last_deviation_time,
+            last_deviation_meters,
+            time_created,
+        })
+    }
+
+    pub fn store(loaded: Mutable<LoadedRoutePlan>) {
+        let mut loaded = loaded.borrow_mut();
+        let items = loaded.items.clone();
+
+        loaded.__account__.items = items;
+
+        let waypoints = loaded.waypoints.borrow().clone();
+
+        loaded.__account__.waypoints = waypoints;
+
+        let waypoint_count = loaded.waypoint_count;
+
+        loaded.__account__.waypoint_count = waypoint_count;
+
+        let corridor_width_meters = loaded.corridor_width_meters;
+
+        loaded.__account__.corridor_width_meters = corridor_width_meters;
+
+        let deviating = loaded.deviating;
+
+        loaded.__account__.deviating = deviating;
+
+        let deviation_count = loaded.deviation_count;
+
+        loaded.__account__.deviation_count = deviation_count;
+
+        let last_deviation_time = loaded.last_deviation_time;
+
+        loaded.__account__.last_deviation_time = last_deviation_time;
+
+        let last_deviation_meters = loaded.last_deviation_meters;
+
+        loaded.__account__.last_deviation_meters = last_deviation_meters;
+
+        let time_created = loaded.time_created;
+
+        loaded.__account__.time_created = time_created;
+    }
+}
+
+#[derive(Debug)]
+pub struct LoadedRoutePlan<'info, 'entrypoint> {
+    pub __account__: &'entrypoint mut Box<Account<'info, RoutePlan>>,
+    pub __programs__: &'entrypoint ProgramsMap<'info>,
+    pub items: Pubkey,
+    pub waypoints: Mutable<[Coordinates; 16]>,
+    pub waypoint_count: u8,
+    pub corridor_width_meters: f64,
+    pub deviating: bool,
+    pub deviation_count: u64,
+    pub last_deviation_time: i64,
+    pub last_deviation_meters: f64,
+    pub time_created: i64,
+}
+
+pub fn depot_authorized<'info>(
+    depot: &Mutable<LoadedDepot<'info, '_>>,
+    signer: Pubkey,
+    depot_role: &Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    hub_depot: &Option<Mutable<LoadedDepot<'info, '_>>>,
+    permission: u8,
+) -> bool {
+    if depot.borrow().owner == signer {
+        return true;
+    }
+
+    // The hub's owner is its first admin, over the hub and the depots below it
+    if let Some(hub_depot) = hub_depot {
+        if depot.borrow().parent_depot != Pubkey::default()
+            && hub_depot.borrow().__account__.key() == depot.borrow().parent_depot
+            && depot.borrow().parent_link_epoch == depot.borrow().owner_epoch
+            && hub_depot.borrow().owner == signer
+        {
+            return true;
+        }
+    }
+
+    if let Some(depot_role) = depot_role {
+        let depot_role = depot_role.borrow();
+
+        if !(depot_role.delegate == signer) {
+            return false;
+        }
+
+        // Admins hold every operational permission of their depot, until its owner changes
+        if depot_role.depot == depot.borrow().__account__.key() {
+            return depot_role.owner_epoch == depot.borrow().owner_epoch
+                && depot_role.permissions & (permission | ROLE_ADMIN!()) != 0;
+        }
+
+        // Hub admins hold the same authority over the depots below their hub, as long as
+        // neither the hub nor the child has changed owner since the role and link were made
+        let hub_depot = match hub_depot {
+            Some(hub_depot) => hub_depot.borrow(),
+            None => return false,
+        };
+
+        return depot.borrow().parent_depot != Pubkey::default()
+            && hub_depot.__account__.key() == depot.borrow().parent_depot
+            && depot_role.depot == depot.borrow().parent_depot
+            && depot_role.owner_epoch == hub_depot.owner_epoch
+            && depot.borrow().parent_link_epoch == depot.borrow().owner_epoch
+            && depot_role.permissions & ROLE_ADMIN!() != 0;
+    }
+
+    return false;
+}
+
+pub fn ed25519_instruction_signed(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    if data.len() < 2 {
+        return false;
+    }
+
+    let signature_count = data[0] as usize;
+
+    for index in 0..signature_count {
+        let start = 2 + index * 14;
+
+        if data.len() < start + 14 {
+            return false;
+        }
+
+        let read = |offset: usize| u16::from_le_bytes([data[start + offset], data[start + offset + 1]]);
+        let public_key_offset = read(4) as usize;
+        let public_key_instruction_index = read(6);
+        let message_offset = read(8) as usize;
+        let message_size = read(10) as usize;
+        let message_instruction_index = read(12);
+
+        // Only accept signatures whose key and message live in the ed25519 instruction itself
+        if public_key_instruction_index != u16::MAX || message_instruction_index != u16::MAX {
+            continue;
+        }
+
+        if data.len() < public_key_offset + 32 || data.len() < message_offset + message_size {
+            continue;
+        }
+
+        if &data[public_key_offset..public_key_offset + 32] == signer.as_ref()
+            && &data[message_offset..message_offset + message_size] == message
+        {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+// SlotHashes sysvar data is a bincode Vec<(Slot, Hash)>, newest slot first
+pub fn slot_hash_in(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    if data.len() < 8 {
+        return None;
+    }
+
+    let entry_count = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+
+    for index in 0..entry_count {
+        let start = 8 + index * 40;
+
+        if data.len() < start + 40 {
+            return None;
+        }
+
+        if u64::from_le_bytes(data[start..start + 8].try_into().unwrap()) == slot {
+            return Some(data[start + 8..start + 40].try_into().unwrap());
+        }
+    }
+
+    return None;
+}
+
+// Receipt message: domain || program id || receipt, so a receipt signature can't be
+// passed off as any other signed message, or replayed against another deployment
+pub fn handoff_receipt_message(program_id: &Pubkey, handoff_receipt: &HandoffReceipt) -> Vec<u8> {
+    let mut message = HANDOFF_RECEIPT_DOMAIN!().to_vec();
+
+    message.extend_from_slice(program_id.as_ref());
+    message.extend_from_slice(&handoff_receipt.try_to_vec().unwrap());
+
+    return message;
+}
+
+pub fn ed25519_signature_verified(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> bool {
+    let current_index =
+        solana_program::sysvar::instructions::load_current_index_checked(instructions).unwrap();
+
+    for index in 0..current_index {
+        let instruction = solana_program::sysvar::instructions::load_instruction_at_checked(
+            index as usize,
+            instructions,
+        )
+        .unwrap();
+
+        if instruction.program_id == solana_program::ed25519_program::ID
+            && ed25519_instruction_signed(&instruction.data, signer, message)
+        {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+pub fn record_sensor_reading<'info>(
+    items: &Mutable<LoadedItems<'info, '_>>,
+    sensor_reading_class: SensorReading,
+    time: i64,
+) -> () {
+    let mut log = items.borrow().environment_log_class.borrow().clone();
+
+    if log.reading_count == 0 {
+        log.temperature_min = sensor_reading_class.temperature;
+        log.temperature_max = sensor_reading_class.temperature;
+        log.humidity_min = sensor_reading_class.humidity;
+        log.humidity_max = sensor_reading_class.humidity;
+    } else {
+        log.temperature_min = log.temperature_min.min(sensor_reading_class.temperature);
+        log.temperature_max = log.temperature_max.max(sensor_reading_class.temperature);
+        log.humidity_min = log.humidity_min.min(sensor_reading_class.humidity);
+        log.humidity_max = log.humidity_max.max(sensor_reading_class.humidity);
+    }
+
+    log.reading_count += 1;
+    log.last_reading_time = time;
+
+    if items.borrow().has_environment_range {
+        let range = items.borrow().environment_range_class.borrow().clone();
+        let in_range = sensor_reading_class.temperature >= range.temperature_min
+            && sensor_reading_class.temperature <= range.temperature_max
+            && sensor_reading_class.humidity >= range.humidity_min
+            && sensor_reading_class.humidity <= range.humidity_max;
+
+        if !in_range {
+            log.excursion_count += 1;
+
+            assign!(items.borrow_mut().non_compliant, true);
+        }
+    }
+
+    assign!(items.borrow_mut().environment_log_class, Mutable::<EnvironmentLog>::new(log));
+}
+
+pub fn multisig_owner_index<'info>(
+    multisig: &Mutable<LoadedMultisig<'info, '_>>,
+    signer: Pubkey,
+) -> Option<usize> {
+    let owner_count = multisig.borrow().owner_count as usize;
+
+    return multisig.borrow().owners.borrow()[..owner_count]
+        .iter()
+        .position(|owner| *owner == signer);
+}
+
+pub fn check_proposal_ready<'info>(
+    multisig: &Mutable<LoadedMultisig<'info, '_>>,
+    proposal: &Mutable<LoadedMultisigProposal<'info, '_>>,
+    depot: &Mutable<LoadedDepot<'info, '_>>,
+) -> () {
+    if !(proposal.borrow().multisig == multisig.borrow().__account__.key()) {
+        panic!("Proposal is not for this multisig");
+    }
+
+    if !(proposal.borrow().depot == depot.borrow().__account__.key()) {
+        panic!("Proposal is not for this depot");
+    }
+
+    if !(depot.borrow().owner == multisig.borrow().__account__.key()) {
+        panic!("Depot is not owned by multisig");
+    }
+
+    if proposal.borrow().executed {
+        panic!("Proposal is already executed");
+    }
+
+    // Approvals only count for the owners and depot owner they were given under
+    if !(proposal.borrow().multisig_owners_epoch == multisig.borrow().owners_epoch) {
+        panic!("Proposal was made under a previous multisig owner set");
+    }
+
+    if !(proposal.borrow().depot_owner_epoch == depot.borrow().owner_epoch) {
+        panic!("Proposal was made under a previous depot owner");
+    }
+
+    if !(proposal.borrow().approval_count >= multisig.borrow().threshold) {
+        panic!("Proposal has not reached the multisig threshold");
+    }
+}
+
+pub fn edit_depot<'info>(
+    depot: &Mutable<LoadedDepot<'info, '_>>,
+    name_array: [u16; 32],
+    info_array: [u16; 256],
+    coordinates_class: Coordinates,
+) -> () {
+    assign!(depot.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(depot.borrow_mut().info_array, Mutable::<[u16; 256]>::new(info_array));
+
+    assign!(depot.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+}
+
+pub fn organization_admin_index<'info>(
+    organization: &Mutable<LoadedOrganization<'info, '_>>,
+    signer: Pubkey,
+) -> Option<usize> {
+    let admin_count = organization.borrow().admin_count as usize;
+
+    return organization.borrow().admins.borrow()[..admin_count]
+        .iter()
+        .position(|admin| *admin == signer);
+}
+
+pub fn internal_transfer_allowed<'info>(
+    organization: &Option<Mutable<LoadedOrganization<'info, '_>>>,
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+) -> bool {
+    if let Some(organization) = organization {
+        let organization_key = organization.borrow().__account__.key();
+
+        return organization.borrow().single_signer_internal_transfers
+            && old_depot.borrow().organization == organization_key
+            && new_depot.borrow().organization == organization_key
+            && old_depot.borrow().organization_owner_epoch == old_depot.borrow().owner_epoch
+            && new_depot.borrow().organization_owner_epoch == new_depot.borrow().owner_epoch;
+    }
+
+    return false;
+}
+
+// Every state-changing instruction checks this, except the config admin's own
+// instructions, which stay available so the admin can respond while paused
+pub fn check_not_paused<'info>(config: &Mutable<LoadedConfig<'info, '_>>) -> () {
+    if config.borrow().paused {
+        panic!("Program is paused");
+    }
+}
+
+pub fn check_config_admin<'info>(config: &Mutable<LoadedConfig<'info, '_>>, admin: Pubkey) -> () {
+    if !(config.borrow().admin == admin) {
+        panic!("Signer is not the config admin");
+    }
+}
+
+pub fn collect_fee<'info>(
+    payer: &SeahorseSigner<'info, '_>,
+    config: &Mutable<LoadedConfig<'info, '_>>,
+    amount: u64,
+) -> () {
+    if amount == 0 {
+        return;
+    }
+
+    solana_program::program::invoke(
+        &solana_program::system_instruction::transfer(
+            &payer.key(),
+            &config.borrow().__account__.key(),
+            amount,
+        ),
+        &[
+            payer.to_account_info(),
+            config.borrow().__account__.to_account_info(),
+            payer.programs.get("system_program"),
+        ],
+    )
+    .unwrap();
+}
+
+pub fn distance_meters(from: &Coordinates, to: &Coordinates) -> f64 {
+    let lat_from = from.lat.to_radians();
+    let lat_to = to.lat.to_radians();
+    let delta_lat = (to.lat - from.lat).to_radians();
+    let delta_long = (to.long - from.long).to_radians();
+    let a = (delta_lat / 2.0).sin().powi(2)
+        + lat_from.cos() * lat_to.cos() * (delta_long / 2.0).sin().powi(2);
+
+    return 2.0 * EARTH_RADIUS_METERS!() * a.sqrt().asin();
+}
+
+pub fn check_max_speed<'info>(
+    config: &Mutable<LoadedConfig<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+    coordinates_class: &Coordinates,
+    time: i64,
+) -> () {
+    let max_speed = config.borrow().max_speed;
+
+    if max_speed <= 0.0 {
+        return;
+    }
+
+    let distance = distance_meters(&items.borrow().coordinates_class.borrow(), coordinates_class);
+    let elapsed = (time - items.borrow().cur_time).max(1) as f64;
+
+    if !(distance / elapsed <= max_speed) {
+        panic!("Items moved faster than the max speed");
+    }
+}
+
+pub fn depot_verified<'info>(
+    depot: &Mutable<LoadedDepot<'info, '_>>,
+    accreditation_authority: &Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    time: i64,
+) -> bool {
+    let depot = depot.borrow();
+
+    // A verification lapses while its issuing authority is deactivated
+    let issuer_active = match accreditation_authority {
+        Some(accreditation_authority) => {
+            accreditation_authority.borrow().authority == depot.verified_by
+                && accreditation_authority.borrow().active
+        }
+        None => false,
+    };
+
+    return depot.verified_by != Pubkey::default()
+        && issuer_active
+        && !depot.verification_revoked
+        && (depot.verification_expiry == 0 || time < depot.verification_expiry);
+}
+
+pub fn check_destination_verified<'info>(
+    time: i64,
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    organization: &Option<Mutable<LoadedOrganization<'info, '_>>>,
+    new_depot_accreditation_authority: &Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let mut required = items.borrow().require_verified_destination;
+    let old_organization = old_depot.borrow().organization;
+
+    // The sending depot's organization policy can't be skipped by leaving the account out
+    if old_organization != Pubkey::default() {
+        match organization {
+            Some(organization) if organization.borrow().__account__.key() == old_organization => {
+                required = required || organization.borrow().require_verified_destination;
+            }
+            _ => panic!("Organization of old depot is required"),
+        }
+    }
+
+    if required && !depot_verified(new_depot, new_depot_accreditation_authority, time) {
+        panic!("New depot is not verified");
+    }
+}
+
+pub fn check_items_not_frozen<'info>(items: &Mutable<LoadedItems<'info, '_>>) -> () {
+    if items.borrow().frozen {
+        panic!("Items are frozen by a regulator");
+    }
+}
+
+pub fn check_active_regulator<'info>(
+    regulator: &Mutable<LoadedRegulator<'info, '_>>,
+    signer: Pubkey,
+) -> () {
+    if !(regulator.borrow().regulator == signer) {
+        panic!("Signer is not the regulator");
+    }
+
+    if !regulator.borrow().active {
+        panic!("Regulator is not active");
+    }
+}
+
+pub fn check_dispatch_inspection<'info>(
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    if !old_depot.borrow().require_inspection_before_dispatch {
+        return;
+    }
+
+    let items = items.borrow();
+    let arrival_time = items.last_transfer_time.max(items.time_created);
+
+    // Only an inspection done at this depot since the items arrived counts
+    if !(items.last_inspection_depot == old_depot.borrow().__account__.key()
+        && items.last_inspection_time >= arrival_time
+        && items.last_inspection_verdict == InspectionVerdict::Pass)
+    {
+        panic!("Items need a passing inspection before leaving old depot");
+    }
+}
+
+pub fn check_certification_window<'info>(
+    time: i64,
+    certifier: &SeahorseSigner<'info, '_>,
+    certifier_account: &Mutable<LoadedCertifier<'info, '_>>,
+    valid_from: i64,
+    valid_until: i64,
+) -> () {
+    if !(certifier_account.borrow().certifier == certifier.key()) {
+        panic!("Signer is not the certifier");
+    }
+
+    if !certifier_account.borrow().active {
+        panic!("Certifier is not active");
+    }
+
+    if !(valid_from < valid_until) {
+        panic!("Certification validity window is empty");
+    }
+
+    if !(valid_until > time) {
+        panic!("Certification is already expired");
+    }
+}
+
+pub fn issue_certification<'info>(
+    time: i64,
+    certifier: &SeahorseSigner<'info, '_>,
+    certifier_account: &Mutable<LoadedCertifier<'info, '_>>,
+    certification: &Empty<Mutable<LoadedCertification<'info, '_>>>,
+    subject: Pubkey,
+    subject_is_depot: bool,
+    scheme: [u8; 16],
+    valid_from: i64,
+    valid_until: i64,
+) -> () {
+    check_certification_window(time, certifier, certifier_account, valid_from, valid_until);
+
+    let mut certification = certification.account.clone();
+
+    assign!(certification.borrow_mut().subject, subject);
+
+    assign!(certification.borrow_mut().subject_is_depot, subject_is_depot);
+
+    assign!(certification.borrow_mut().certifier, certifier.key());
+
+    assign!(certification.borrow_mut().scheme, Mutable::<[u8; 16]>::new(scheme));
+
+    assign!(certification.borrow_mut().valid_from, valid_from);
+
+    assign!(certification.borrow_mut().valid_until, valid_until);
+
+    assign!(certification.borrow_mut().time_created, time);
+}
+
+pub fn document_type_bit(document_type: DocumentType) -> u8 {
+    return 1 << (document_type as u8);
+}
+
+pub fn check_boundary_documents<'info>(
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    // The boundary is a change of country, taken from the depots' jurisdiction codes;
+    // a depot without a jurisdiction can't show it is in the same country
+    let old_jurisdiction_code = old_depot.borrow().jurisdiction_code.borrow().clone();
+    let new_jurisdiction_code = new_depot.borrow().jurisdiction_code.borrow().clone();
+
+    if old_jurisdiction_code[..2] != [0u8; 2] && old_jurisdiction_code[..2] == new_jurisdiction_code[..2] {
+        return;
+    }
+
+    let required = old_depot.borrow().boundary_required_documents
+        | new_depot.borrow().boundary_required_documents;
+
+    if !(items.borrow().pending_document_types & required == required) {
+        panic!("Transfer is missing documents required to cross the depot boundary");
+    }
+}
+
+pub fn jurisdiction_matches(rule_code: &[u8; 6], code: &[u8; 6]) -> bool {
+    // An empty rule code matches any jurisdiction
+    if *rule_code == [0u8; 6] {
+        return true;
+    }
+
+    if rule_code == code {
+        return true;
+    }
+
+    // A bare country code also matches its regions, e.g. "US" matches "US-CA"
+    return rule_code[2..].iter().all(|byte| *byte == 0)
+        && code[..2] == rule_code[..2]
+        && code[2] == b'-';
+}
+
+pub fn hs_code_matches(rule: &TransferRule, hs_code: u32) -> bool {
+    if rule.hs_prefix_digits > HS_CODE_DIGITS!() {
+        return false;
+    }
+
+    let divisor = 10u32.pow((HS_CODE_DIGITS!() - rule.hs_prefix_digits) as u32);
+
+    return hs_code / divisor == rule.hs_prefix;
+}
+
+pub fn check_transfer_rules<'info>(
+    transfer_rules: &Mutable<LoadedTransferRules<'info, '_>>,
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let origin_code = old_depot.borrow().jurisdiction_code.borrow().clone();
+    let destination_code = new_depot.borrow().jurisdiction_code.borrow().clone();
+    let classification = items.borrow().classification_class.borrow().clone();
+    let rules = transfer_rules.borrow().rules.borrow().clone();
+
+    for rule in rules[..transfer_rules.borrow().rule_count as usize].iter() {
+        if !(jurisdiction_matches(&rule.origin_code, &origin_code)
+            && jurisdiction_matches(&rule.destination_code, &destination_code))
+        {
+            continue;
+        }
+
+        // A rule only forbids items that meet every goods criterion it sets
+        let hazmat_matches = rule.hazmat_class == 0 || rule.hazmat_class == classification.hazmat_class;
+        let hs_matches = rule.hs_prefix_digits == 0 || hs_code_matches(rule, classification.hs_code);
+
+        if !(hazmat_matches && hs_matches) {
+            continue;
+        }
+
+        if rule.hazmat_class != 0 {
+            panic!("Hazmat class of items is forbidden between these jurisdictions");
+        } else if rule.hs_prefix_digits != 0 {
+            panic!("HS code of items is forbidden between these jurisdictions");
+        } else {
+            panic!("Transfers between these jurisdictions are forbidden");
+        }
+    }
+}
+
+pub fn check_hazmat_licensed<'info>(depot: &Mutable<LoadedDepot<'info, '_>>, hazmat_class: u8) -> () {
+    if hazmat_class == 0 {
+        return;
+    }
+
+    if !(depot.borrow().licensed_hazmat_classes & (1 << hazmat_class) != 0) {
+        panic!("Depot is not licensed for the hazmat class of items");
+    }
+}
+
+pub fn add_items_to_depot<'info>(
+    depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let item_count = depot.borrow().item_count.checked_add(1).unwrap();
+    let total_weight_grams = depot
+        .borrow()
+        .total_weight_grams
+        .checked_add(items.borrow().weight_grams)
+        .unwrap();
+
+    let total_volume_cm3 = depot
+        .borrow()
+        .total_volume_cm3
+        .checked_add(items.borrow().volume_cm3)
+        .unwrap();
+
+    // A limit of 0 means the depot has no limit
+    if !(depot.borrow().max_item_count == 0 || item_count <= depot.borrow().max_item_count) {
+        panic!("Depot item count capacity exceeded");
+    }
+
+    if !(depot.borrow().max_weight_grams == 0 || total_weight_grams <= depot.borrow().max_weight_grams) {
+        panic!("Depot weight capacity exceeded");
+    }
+
+    if !(depot.borrow().max_volume_cm3 == 0 || total_volume_cm3 <= depot.borrow().max_volume_cm3) {
+        panic!("Depot volume capacity exceeded");
+    }
+
+    assign!(depot.borrow_mut().item_count, item_count);
+
+    assign!(depot.borrow_mut().total_weight_grams, total_weight_grams);
+
+    assign!(depot.borrow_mut().total_volume_cm3, total_volume_cm3);
+}
+
+pub fn remove_items_from_depot<'info>(
+    depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let item_count = depot.borrow().item_count.checked_sub(1).unwrap();
+    let total_weight_grams = depot
+        .borrow()
+        .total_weight_grams
+        .checked_sub(items.borrow().weight_grams)
+        .unwrap();
+
+    let total_volume_cm3 = depot
+        .borrow()
+        .total_volume_cm3
+        .checked_sub(items.borrow().volume_cm3)
+        .unwrap();
+
+    assign!(depot.borrow_mut().item_count, item_count);
+
+    assign!(depot.borrow_mut().total_weight_grams, total_weight_grams);
+
+    assign!(depot.borrow_mut().total_volume_cm3, total_volume_cm3);
+}
+
+// Hub totals are eventually consistent: transfers only update the depots they touch,
+// so a parent's children_* totals reflect each child as of its last roll-up, which
+// happens when the child is accepted and whenever anyone calls sync_depot_rollup
+pub fn roll_up_depot<'info>(
+    child_depot: &Mutable<LoadedDepot<'info, '_>>,
+    parent_depot: &Mutable<LoadedDepot<'info, '_>>,
+) -> () {
+    if !(child_depot.borrow().parent_depot == parent_depot.borrow().__account__.key()) {
+        panic!("Parent depot is not the parent of child depot");
+    }
+
+    // A child reports its own totals plus everything below it
+    let child = child_depot.borrow();
+    let item_count = child.item_count.checked_add(child.children_item_count).unwrap();
+    let weight_grams = child
+        .total_weight_grams
+        .checked_add(child.children_weight_grams)
+        .unwrap();
+
+    let volume_cm3 = child
+        .total_volume_cm3
+        .checked_add(child.children_volume_cm3)
+        .unwrap();
+
+    let reported_item_count = child.reported_item_count;
+    let reported_weight_grams = child.reported_weight_grams;
+    let reported_volume_cm3 = child.reported_volume_cm3;
+
+    drop(child);
+
+    let children_item_count = parent_depot
+        .borrow()
+        .children_item_count
+        .checked_sub(reported_item_count)
+        .and_then(|count| count.checked_add(item_count))
+        .unwrap();
+
+    let children_weight_grams = parent_depot
+        .borrow()
+        .children_weight_grams
+        .checked_sub(reported_weight_grams)
+        .and_then(|weight| weight.checked_add(weight_grams))
+        .unwrap();
+
+    let children_volume_cm3 = parent_depot
+        .borrow()
+        .children_volume_cm3
+        .checked_sub(reported_volume_cm3)
+        .and_then(|volume| volume.checked_add(volume_cm3))
+        .unwrap();
+
+    assign!(parent_depot.borrow_mut().children_item_count, children_item_count);
+
+    assign!(parent_depot.borrow_mut().children_weight_grams, children_weight_grams);
+
+    assign!(parent_depot.borrow_mut().children_volume_cm3, children_volume_cm3);
+
+    assign!(child_depot.borrow_mut().reported_item_count, item_count);
+
+    assign!(child_depot.borrow_mut().reported_weight_grams, weight_grams);
+
+    assign!(child_depot.borrow_mut().reported_volume_cm3, volume_cm3);
+}
+
+pub fn within_receiving_hours(receiving_hours: &ReceivingHours, time: i64) -> bool {
+    let local_time = time + (receiving_hours.utc_offset_minutes as i64) * 60;
+    // 1970-01-01 was a Thursday, index 0 is Sunday
+    let weekday = (local_time.div_euclid(86400) + 4).rem_euclid(7) as usize;
+    let minute = (local_time.rem_euclid(86400) / 60) as u16;
+
+    return receiving_hours.open_minutes[weekday] <= minute && minute < receiving_hours.close_minutes[weekday];
+}
+
+pub fn check_receiving_hours<'info>(
+    time: i64,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+    new_depot_owner_signed: bool,
+) -> () {
+    let after_hours = new_depot.borrow().has_receiving_hours
+        && !new_depot_owner_signed
+        && !within_receiving_hours(&new_depot.borrow().receiving_hours_class.borrow(), time);
+
+    assign!(items.borrow_mut().received_after_hours, after_hours);
+
+    if !after_hours {
+        return;
+    }
+
+    if new_depot.borrow().reject_after_hours {
+        panic!("New depot is not receiving at this time");
+    }
+
+    let after_hours_receipt_count = new_depot.borrow().after_hours_receipt_count + 1;
+
+    assign!(new_depot.borrow_mut().after_hours_receipt_count, after_hours_receipt_count);
+}
+
+pub fn partner_transfer_allowed<'info>(
+    time: i64,
+    partner_agreement: &Option<Mutable<LoadedPartnerAgreement<'info, '_>>>,
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> bool {
+    let partner_agreement = match partner_agreement {
+        Some(partner_agreement) => partner_agreement,
+        None => return false,
+    };
+
+    let old_depot_key = old_depot.borrow().__account__.key();
+    let new_depot_key = new_depot.borrow().__account__.key();
+    let (depot_a, depot_b) = if old_depot_key < new_depot_key {
+        (old_depot_key, new_depot_key)
+    } else {
+        (new_depot_key, old_depot_key)
+    };
+
+    if !(partner_agreement.borrow().depot_a == depot_a && partner_agreement.borrow().depot_b == depot_b) {
+        return false;
+    }
+
+    // Agreements lapse once either depot changes owner
+    let (depot_a_owner_epoch, depot_b_owner_epoch) = if old_depot_key < new_depot_key {
+        (old_depot.borrow().owner_epoch, new_depot.borrow().owner_epoch)
+    } else {
+        (new_depot.borrow().owner_epoch, old_depot.borrow().owner_epoch)
+    };
+
+    if !(partner_agreement.borrow().depot_a_owner_epoch == depot_a_owner_epoch
+        && partner_agreement.borrow().depot_b_owner_epoch == depot_b_owner_epoch)
+    {
+        return false;
+    }
+
+    if !(time < partner_agreement.borrow().expiry_time) {
+        return false;
+    }
+
+    // An empty chapter set places no limit on item categories
+    let allowed_hs_chapters = partner_agreement.borrow().allowed_hs_chapters.borrow().clone();
+    let hs_chapter = (items.borrow().classification_class.borrow().hs_code / 10000) as usize;
+
+    if allowed_hs_chapters != [0u8; 13]
+        && (hs_chapter >= 100 || allowed_hs_chapters[hs_chapter / 8] & (1 << (hs_chapter % 8)) == 0)
+    {
+        return false;
+    }
+
+    let day = time.div_euclid(86400);
+    let transfers_today = if partner_agreement.borrow().transfer_day == day {
+        partner_agreement.borrow().transfers_today + 1
+    } else {
+        1
+    };
+
+    if !(partner_agreement.borrow().max_transfers_per_day == 0
+        || transfers_today <= partner_agreement.borrow().max_transfers_per_day)
+    {
+        return false;
+    }
+
+    assign!(partner_agreement.borrow_mut().transfer_day, day);
+
+    assign!(partner_agreement.borrow_mut().transfers_today, transfers_today);
+
+    return true;
+}
+
+pub fn check_items_not_in_transit<'info>(items: &Mutable<LoadedItems<'info, '_>>) -> () {
+    if !(items.borrow().carrier == Pubkey::default()) {
+        panic!("Items are held by a carrier");
+    }
+}
+
+pub fn reset_sub_location<'info>(items: &Mutable<LoadedItems<'info, '_>>) -> () {
+    assign!(items.borrow_mut().sub_location_class, Mutable::<SubLocation>::new(SubLocation::default()));
+
+    assign!(items.borrow_mut().sub_location_updated_by, Pubkey::default());
+
+    assign!(items.borrow_mut().sub_location_time, 0);
+}
+
+pub fn distance_to_segment_meters(point: &Coordinates, from: &Coordinates, to: &Coordinates) -> f64 {
+    // Flat projection around the segment start, fine at corridor scale
+    let meters_per_degree = EARTH_RADIUS_METERS!() * std::f64::consts::PI / 180.0;
+    let long_scale = from.lat.to_radians().cos();
+    let segment_x = (to.long - from.long) * long_scale * meters_per_degree;
+    let segment_y = (to.lat - from.lat) * meters_per_degree;
+    let point_x = (point.long - from.long) * long_scale * meters_per_degree;
+    let point_y = (point.lat - from.lat) * meters_per_degree;
+    let segment_length_squared = segment_x * segment_x + segment_y * segment_y;
+
+    if segment_length_squared == 0.0 {
+        return distance_meters(point, from);
+    }
+
+    let t = ((point_x * segment_x + point_y * segment_y) / segment_length_squared).clamp(0.0, 1.0);
+    let offset_x = point_x - t * segment_x;
+    let offset_y = point_y - t * segment_y;
+
+    return (offset_x * offset_x + offset_y * offset_y).sqrt();
+}
+
+pub fn check_route<'info>(
+    route_plan: &Option<Mutable<LoadedRoutePlan<'info, '_>>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+    coordinates_class: &Coordinates,
+    time: i64,
+) -> () {
+    if !items.borrow().has_route_plan {
+        return;
+    }
+
+    let route_plan = match route_plan {
+        Some(route_plan) => route_plan,
+        None => panic!("Route plan of items is required"),
+    };
+
+    if !(route_plan.borrow().items == items.borrow().__account__.key()) {
+        panic!("Route plan is not for these items");
+    }
+
+    let waypoints = route_plan.borrow().waypoints.borrow().clone();
+    let waypoint_count = route_plan.borrow().waypoint_count as usize;
+    let mut distance = distance_meters(coordinates_class, &waypoints[0]);
+
+    for index in 1..waypoint_count {
+        distance = distance.min(distance_to_segment_meters(
+            coordinates_class,
+            &waypoints[index - 1],
+            &waypoints[index],
+        ));
+    }
+
+    let deviating = distance > route_plan.borrow().corridor_width_meters;
+
+    // Count each excursion from the corridor once
+    if deviating && !route_plan.borrow().deviating {
+        let deviation_count = route_plan.borrow().deviation_count + 1;
+
+        assign!(route_plan.borrow_mut().deviation_count, deviation_count);
+    }
+
+    if deviating {
+        assign!(route_plan.borrow_mut().last_deviation_time, time);
+
+        assign!(route_plan.borrow_mut().last_deviation_meters, distance);
+    }
+
+    assign!(route_plan.borrow_mut().deviating, deviating);
+}
+
+pub fn record_arrival<'info>(
+    time: i64,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let delivery_status = items.borrow().delivery_status;
+
+    if !(delivery_status == DeliveryStatus::Pending || delivery_status == DeliveryStatus::Overdue) {
+        return;
+    }
+
+    if !(items.borrow().destination_depot == new_depot.borrow().__account__.key()) {
+        return;
+    }
+
+    let lateness_seconds = (time - items.borrow().delivery_deadline).max(0);
+    let delivery_status = if lateness_seconds == 0 {
+        DeliveryStatus::OnTime
+    } else {
+        DeliveryStatus::Late
+    };
+
+    assign!(items.borrow_mut().delivery_status, delivery_status);
+
+    assign!(items.borrow_mut().arrival_time, time);
+
+    assign!(items.borrow_mut().lateness_seconds, lateness_seconds);
+}
+
+pub fn check_items_not_delivered<'info>(items: &Mutable<LoadedItems<'info, '_>>) -> () {
+    if items.borrow().delivered {
+        panic!("Items are already delivered to their consignee");
+    }
+}
+
+pub fn set_depot_owner<'info>(depot: &Mutable<LoadedDepot<'info, '_>>, new_owner: Pubkey) -> () {
+    let owner_epoch = depot.borrow().owner_epoch + 1;
+
+    assign!(depot.borrow_mut().owner, new_owner);
+
+    // Roles, partner agreements, hub links and organization shortcuts were
+    // granted by the previous owner and stop applying from here on
+    assign!(depot.borrow_mut().owner_epoch, owner_epoch);
+}
+
+pub fn check_multisig_owners(owners: &[Pubkey; 10], owner_count: u8, threshold: u8) -> () {
+    if !(owner_count > 0 && owner_count as usize <= MULTISIG_MAX_OWNERS!()) {
+        panic!("Multisig owner count is out of range");
+    }
+
+    if !(threshold > 0 && threshold <= owner_count) {
+        panic!("Multisig threshold is out of range");
+    }
+
+    for index in 0..owner_count as usize {
+        if !(owners[index] != Pubkey::default()) {
+            panic!("Multisig owner is empty");
+        }
+
+        if owners[..index].contains(&owners[index]) {
+            panic!("Multisig owner is duplicated");
+        }
+    }
+}
+
+pub fn check_role_permissions(permissions: u8) -> () {
+    if !(permissions != 0) {
+        panic!("Delegate permissions are empty");
+    }
+
+    if !(permissions & !(ROLE_ADMIN!() | ROLE_RECEIVER!() | ROLE_SHIPPER!() | ROLE_AUDITOR!()) == 0) {
+        panic!("Delegate permissions are unknown");
+    }
+}
+
+pub fn grant_depot_role<'info>(
+    depot: &Mutable<LoadedDepot<'info, '_>>,
+    depot_role: &Mutable<LoadedDepotRole<'info, '_>>,
+    delegate: Pubkey,
+    permissions: u8,
+    granted_by: Pubkey,
+    time: i64,
+) -> () {
+    assign!(depot_role.borrow_mut().depot, depot.borrow().__account__.key());
+
+    assign!(depot_role.borrow_mut().delegate, delegate);
+
+    assign!(depot_role.borrow_mut().permissions, permissions);
+
+    assign!(depot_role.borrow_mut().granted_by, granted_by);
+
+    assign!(depot_role.borrow_mut().owner_epoch, depot.borrow().owner_epoch);
+
+    assign!(depot_role.borrow_mut().time_created, time);
+}
+
+pub fn check_new_depot_accepts<'info>(
+    time: i64,
+    new_depot_owner: &Option<SeahorseSigner<'info, '_>>,
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot_owner_role: &Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    new_depot_owner_hub_depot: &Option<Mutable<LoadedDepot<'info, '_>>>,
+    organization: &Option<Mutable<LoadedOrganization<'info, '_>>>,
+    partner_agreement: &Option<Mutable<LoadedPartnerAgreement<'info, '_>>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+) -> bool {
+    match new_depot_owner {
+        Some(new_depot_owner) => {
+            if !depot_authorized(
+                new_depot,
+                new_depot_owner.key(),
+                new_depot_owner_role,
+                new_depot_owner_hub_depot,
+                ROLE_RECEIVER!(),
+            ) {
+                panic!("New depot owner is not the owner or a receiver of new depot");
+            }
+
+            return new_depot_owner.key() == new_depot.borrow().owner;
+        }
+        None => {
+            if !(internal_transfer_allowed(organization, old_depot, new_depot)
+                || partner_transfer_allowed(time, partner_agreement, old_depot, new_depot, items))
+            {
+                panic!("New depot owner signature is required");
+            }
+
+            return false;
+        }
+    }
+}
+
+pub fn init_depot_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut depot: Empty<Mutable<LoadedDepot<'info, '_>>>,
+    mut name_array: [u16; 32],
+    mut info_array: [u16; 256],
+    mut coordinates_class: Coordinates,
+    mut seed_random: u128,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+    let mut depot = depot.account.clone();
+
+    collect_fee(&payer, &config, config.borrow().depot_fee);
+
+    assign!(depot.borrow_mut().owner, owner.key());
+
+    assign!(depot.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(depot.borrow_mut().info_array, Mutable::<[u16; 256]>::new(info_array));
+
+    assign!(depot.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+
+    assign!(depot.borrow_mut().time_created, time);
+}
+
+pub fn init_items_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut depot_signer: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut depot_signer_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut depot_signer_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut items: Empty<Mutable<LoadedItems<'info, '_>>>,
+    mut name_array: [u16; 32],
+    mut info_array: [u16; 256],
+    mut coordinates_class: Coordinates,
+    mut seed_random: u128,
+    mut expiry_time: i64,
+    mut environment_range_class: Option<EnvironmentRange>,
+    mut tag_pubkey: Pubkey,
+    mut classification_class: GoodsClassification,
+    mut weight_grams: u64,
+    mut volume_cm3: u64,
+    mut consignee: Pubkey,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !(classification_class.hs_code < 10u32.pow(HS_CODE_DIGITS!())) {
+        panic!("HS code has more than six digits");
+    }
+
+    if !(classification_class.hazmat_class <= HAZMAT_CLASS_MAX!()) {
+        panic!("Hazmat class is not a valid UN class");
+    }
+
+    check_hazmat_licensed(&depot, classification_class.hazmat_class);
+
+    if !depot_authorized(
+        &depot,
+        depot_signer.key(),
+        &depot_signer_role,
+        &depot_signer_hub_depot,
+        ROLE_RECEIVER!(),
+    ) {
+        panic!("Depot signer is not the owner or a receiver of depot");
+    }
+
+    collect_fee(&payer, &config, config.borrow().items_fee);
+
+    let mut items = items.account.clone();
+
+    assign!(items.borrow_mut().owner, owner.key());
+
+    assign!(items.borrow_mut().depot, depot.borrow().__account__.key());
+
+    assign!(items.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(items.borrow_mut().info_array, Mutable::<[u16; 256]>::new(info_array));
+
+    assign!(items.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+
+    assign!(items.borrow_mut().cur_time, time);
+
+    assign!(items.borrow_mut().time_created, time);
+
+    assign!(items.borrow_mut().origin_depot, depot.borrow().__account__.key());
+
+    assign!(items.borrow_mut().last_updated_by, owner.key());
+
+    assign!(items.borrow_mut().expiry_time, expiry_time);
+
+    assign!(items.borrow_mut().tag_pubkey, tag_pubkey);
+
+    assign!(items.borrow_mut().classification_class, Mutable::<GoodsClassification>::new(classification_class));
+
+    assign!(items.borrow_mut().weight_grams, weight_grams);
+
+    assign!(items.borrow_mut().volume_cm3, volume_cm3);
+
+    assign!(items.borrow_mut().consignee, consignee);
+
+    add_items_to_depot(&depot, &items);
+
+    if let Some(environment_range_class) = environment_range_class {
+        if !(environment_range_class.temperature_min <= environment_range_class.temperature_max
+            && environment_range_class.humidity_min <= environment_range_class.humidity_max)
+        {
+            panic!("Environment range min is greater than max");
+        }
+
+        assign!(items.borrow_mut().has_environment_range, true);
+
+        assign!(items.borrow_mut().environment_range_class, Mutable::<EnvironmentRange>::new(environment_range_class));
+    }
+}
+
+pub fn check_items_compliant<'info>(
+    time: i64,
+    items: &Mutable<LoadedItems<'info, '_>>,
+    override_compliance: bool,
+) -> () {
+    let expired = !(items.borrow().expiry_time == 0 || time < items.borrow().expiry_time);
+    let non_compliant = items.borrow().non_compliant;
+
+    if !(expired || non_compliant) {
+        return;
+    }
+
+    if !override_compliance {
+        if expired {
+            panic!("Items are expired");
+        }
+
+        panic!("Items are not compliant with their environment range");
+    }
+
+    // Overrides that actually let failing items through stay on the items' record
+    let compliance_override_count = items.borrow().compliance_override_count + 1;
+
+    assign!(items.borrow_mut().compliance_override_count, compliance_override_count);
+
+    assign!(items.borrow_mut().last_compliance_override_time, time);
+}
+
+pub fn move_items_to_depot<'info>(
+    time: i64,
+    received_time: i64,
+    old_depot: &Mutable<LoadedDepot<'info, '_>>,
+    new_depot: &Mutable<LoadedDepot<'info, '_>>,
+    transfer_rules: &Mutable<LoadedTransferRules<'info, '_>>,
+    organization: &Option<Mutable<LoadedOrganization<'info, '_>>>,
+    new_depot_accreditation_authority: &Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    items: &Mutable<LoadedItems<'info, '_>>,
+    override_compliance: bool,
+    new_depot_owner_signed: bool,
+) -> () {
+    if !(items.borrow().depot == old_depot.borrow().__account__.key()) {
+        panic!("Old depot is not same as items depot");
+    }
+
+    if !(old_depot.borrow().__account__.key() != new_depot.borrow().__account__.key()) {
+        panic!("Old depot is same as new depot");
+    }
+
+    check_items_not_frozen(items);
+
+    check_items_not_delivered(items);
+
+    check_dispatch_inspection(old_depot, items);
+
+    check_destination_verified(
+        time,
+        old_depot,
+        new_depot,
+        organization,
+        new_depot_accreditation_authority,
+        items,
+    );
+
+    check_boundary_documents(old_depot, new_depot, items);
+
+    check_transfer_rules(transfer_rules, old_depot, new_depot, items);
+
+    check_hazmat_licensed(new_depot, items.borrow().classification_class.borrow().hazmat_class);
+
+    // Receiving hours apply to when the goods physically changed hands
+    check_receiving_hours(received_time, new_depot, items, new_depot_owner_signed);
+
+    check_items_compliant(time, items, override_compliance);
+
+    remove_items_from_depot(old_depot, items);
+
+    add_items_to_depot(new_depot, items);
+
+    assign!(
+        items.borrow_mut().depot,
+        new_depot.borrow().__account__.key()
+    );
+
+    assign!(items.borrow_mut().cur_time, time);
+
+    assign!(items.borrow_mut().last_transfer_time, time);
+
+    record_arrival(time, new_depot, items);
+
+    // A bin in the old depot means nothing in the new one
+    reset_sub_location(items);
+
+    let transfer_count = items.borrow().transfer_count + 1;
+
+    assign!(items.borrow_mut().transfer_count, transfer_count);
+
+    // Documents attached to the next transfer are used up by this one
+    assign!(items.borrow_mut().pending_document_types, 0);
+}
+
+pub fn transfer_items_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Mutable<LoadedTransferRules<'info, '_>>,
+    mut old_depot_owner: SeahorseSigner<'info, '_>,
+    mut new_depot_owner: Option<SeahorseSigner<'info, '_>>,
+    mut old_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut old_depot_owner_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut old_depot_owner_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut new_depot_owner_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut new_depot_owner_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut organization: Option<Mutable<LoadedOrganization<'info, '_>>>,
+    mut partner_agreement: Option<Mutable<LoadedPartnerAgreement<'info, '_>>>,
+    mut new_depot_accreditation_authority: Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut override_compliance: bool,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &old_depot,
+        old_depot_owner.key(),
+        &old_depot_owner_role,
+        &old_depot_owner_hub_depot,
+        ROLE_SHIPPER!(),
+    ) {
+        panic!("Old depot owner is not the owner or a shipper of old depot");
+    }
+
+    let new_depot_owner_signed = check_new_depot_accepts(
+        time,
+        &new_depot_owner,
+        &old_depot,
+        &new_depot,
+        &new_depot_owner_role,
+        &new_depot_owner_hub_depot,
+        &organization,
+        &partner_agreement,
+        &items,
+    );
+
+    move_items_to_depot(
+        time,
+        time,
+        &old_depot,
+        &new_depot,
+        &transfer_rules,
+        &organization,
+        &new_depot_accreditation_authority,
+        &items,
+        override_compliance,
+        new_depot_owner_signed,
+    );
+}
+
+pub fn update_items_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut route_plan: Option<Mutable<LoadedRoutePlan<'info, '_>>>,
+    mut coordinates_class: Coordinates,
+    mut sensor_reading_class: Option<SensorReading>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner");
+    }
+
+    check_items_not_frozen(&items);
+
+    check_items_not_in_transit(&items);
+
+    check_max_speed(&config, &items, &coordinates_class, time);
+
+    check_route(&route_plan, &items, &coordinates_class, time);
+
+    if let Some(sensor_reading_class) = sensor_reading_class {
+        record_sensor_reading(&items, sensor_reading_class, time);
+    }
+
+    assign!(items.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+
+    assign!(items.borrow_mut().cur_time, time);
+
+    assign!(items.borrow_mut().last_updated_by, owner.key());
+}
+
+pub fn authorize_device_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut device_authorization: Empty<Mutable<LoadedDeviceAuthorization<'info, '_>>>,
+    mut device: Pubkey,
+    mut expiry_time: i64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().depot == depot.borrow().__account__.key()) {
+        panic!("Depot is not same as items depot");
+    }
+
+    if !(items.borrow().owner == authority.key() || depot.borrow().owner == authority.key()) {
+        panic!("Authority is not the owner of items or depot");
+    }
+
+    if !(expiry_time == 0 || expiry_time > time) {
+        panic!("Device authorization expiry is in the past");
+    }
+
+    let mut device_authorization = device_authorization.account.clone();
+
+    assign!(device_authorization.borrow_mut().items, items.borrow().__account__.key());
+
+    assign!(device_authorization.borrow_mut().device, device);
+
+    assign!(device_authorization.borrow_mut().authority, authority.key());
+
+    assign!(device_authorization.borrow_mut().depot, depot.borrow().__account__.key());
+
+    assign!(device_authorization.borrow_mut().expiry_time, expiry_time);
+
+    assign!(device_authorization.borrow_mut().time_created, time);
+}
+
+pub fn revoke_device_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut device_authorization: Mutable<LoadedDeviceAuthorization<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !(device_authorization.borrow().items == items.borrow().__account__.key()) {
+        panic!("Device authorization is not for these items");
+    }
+
+    // Whoever granted the device can always take it back, even after the items move on
+    let depot_owner_signed = items.borrow().depot == depot.borrow().__account__.key()
+        && depot.borrow().owner == authority.key();
+
+    if !(device_authorization.borrow().authority == authority.key()
+        || items.borrow().owner == authority.key()
+        || depot_owner_signed)
+    {
+        panic!("Authority is not the granting authority or the owner of items or depot");
+    }
+}
+
+pub fn update_items_location_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut device: SeahorseSigner<'info, '_>,
+    mut device_authorization: Mutable<LoadedDeviceAuthorization<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut route_plan: Option<Mutable<LoadedRoutePlan<'info, '_>>>,
+    mut coordinates_class: Coordinates,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(device_authorization.borrow().items == items.borrow().__account__.key()) {
+        panic!("Device authorization is not for these items");
+    }
+
+    if !(device_authorization.borrow().device == device.key()) {
+        panic!("Device is not authorized for these items");
+    }
+
+    // Authorizations are granted by a depot and lapse once the items leave it
+    if !(device_authorization.borrow().depot == items.borrow().depot) {
+        panic!("Device authorization was granted by a depot that no longer holds these items");
+    }
+
+    if !(device_authorization.borrow().expiry_time == 0 || time < device_authorization.borrow().expiry_time) {
+        panic!("Device authorization is expired");
+    }
+
+    check_items_not_frozen(&items);
+
+    check_items_not_in_transit(&items);
+
+    check_max_speed(&config, &items, &coordinates_class, time);
+
+    check_route(&route_plan, &items, &coordinates_class, time);
+
+    assign!(items.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+
+    assign!(items.borrow_mut().cur_time, time);
+
+    assign!(items.borrow_mut().last_updated_by, device.key());
+
+    assign!(device_authorization.borrow_mut().last_update_time, time);
+}
+
+pub fn verify_tag_scan_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut instructions: AccountInfo<'info>,
+    mut slot_hashes: AccountInfo<'info>,
+    mut scan_time: i64,
+    mut coordinates_class: Coordinates,
+    mut recent_slot: u64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().tag_pubkey != Pubkey::default()) {
+        panic!("Items are not bound to a tag");
+    }
+
+    if !(scan_time <= time && time - scan_time <= TAG_SCAN_MAX_AGE!()) {
+        panic!("Tag scan challenge is not fresh");
+    }
+
+    if !(scan_time > items.borrow().last_scan_time) {
+        panic!("Tag scan is older than the last verified scan");
+    }
+
+    // Slot hashes only cover the last few minutes of slots, and can't be known in advance
+    let recent_slot_hash = match slot_hash_in(&slot_hashes.data.borrow(), recent_slot) {
+        Some(recent_slot_hash) => recent_slot_hash,
+        None => panic!("Tag scan slot is not recent"),
+    };
+
+    // Challenge: items key || scan counter || recent slot hash || scan time || latitude || longitude,
+    // so a captured signature cannot be replayed, a tag cannot sign scans ahead of time and the
+    // recorded location is the one the tag signed
+    let mut challenge = items.borrow().__account__.key().to_bytes().to_vec();
+
+    challenge.extend_from_slice(&items.borrow().tag_scan_count.to_le_bytes());
+    challenge.extend_from_slice(&recent_slot_hash);
+    challenge.extend_from_slice(&scan_time.to_le_bytes());
+    challenge.extend_from_slice(&coordinates_class.lat.to_le_bytes());
+    challenge.extend_from_slice(&coordinates_class.long.to_le_bytes());
+
+    if !ed25519_signature_verified(&instructions, &items.borrow().tag_pubkey, &challenge) {
+        panic!("Tag signature is not verified");
+    }
+
+    let tag_scan_count = items.borrow().tag_scan_count + 1;
+
+    assign!(items.borrow_mut().tag_scan_count, tag_scan_count);
+
+    assign!(items.borrow_mut().last_scan_time, scan_time);
+
+    assign!(items.borrow_mut().last_scan_coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+}
+
+pub fn transfer_items_with_receipt_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Mutable<LoadedTransferRules<'info, '_>>,
+    mut old_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut organization: Option<Mutable<LoadedOrganization<'info, '_>>>,
+    mut new_depot_accreditation_authority: Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut instructions: AccountInfo<'info>,
+    mut handoff_receipt_class: HandoffReceipt,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !(handoff_receipt_class.items == items.borrow().__account__.key()) {
+        panic!("Handoff receipt is not for these items");
+    }
+
+    if !(handoff_receipt_class.old_depot == old_depot.borrow().__account__.key()
+        && handoff_receipt_class.new_depot == new_depot.borrow().__account__.key())
+    {
+        panic!("Handoff receipt depots do not match");
+    }
+
+    if !(handoff_receipt_class.timestamp <= time) {
+        panic!("Handoff receipt is in the future");
+    }
+
+    // A receipt must be newer than the last custody change, so it can't be replayed
+    if !(handoff_receipt_class.timestamp > items.borrow().last_transfer_time) {
+        panic!("Handoff receipt is older than the last transfer");
+    }
+
+    let message = handoff_receipt_message(&id(), &handoff_receipt_class);
+
+    // Both owners must be single keys; multisig depots use transfer_items_from_multisig_depot
+    if !ed25519_signature_verified(&instructions, &old_depot.borrow().owner, &message) {
+        panic!("Handoff receipt is not signed by old depot owner");
+    }
+
+    if !ed25519_signature_verified(&instructions, &new_depot.borrow().owner, &message) {
+        panic!("Handoff receipt is not signed by new depot owner");
+    }
+
+    move_items_to_depot(
+        time,
+        handoff_receipt_class.timestamp,
+        &old_depot,
+        &new_depot,
+        &transfer_rules,
+        &organization,
+        &new_depot_accreditation_authority,
+        &items,
+        false,
+        true,
+    );
+}
+
+pub fn add_depot_delegate_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut depot_role: Empty<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut delegate: Pubkey,
+    mut permissions: u8,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    check_role_permissions(permissions);
+
+    if !(permissions & ROLE_ADMIN!() == 0 || depot.borrow().owner == authority.key()) {
+        panic!("Only depot owner can grant admin role");
+    }
+
+    let mut depot_role = depot_role.account.clone();
+
+    grant_depot_role(&depot, &depot_role, delegate, permissions, authority.key(), time);
+}
+
+pub fn remove_depot_delegate_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut depot_role: Mutable<LoadedDepotRole<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    if !(depot_role.borrow().depot == depot.borrow().__account__.key()) {
+        panic!("Depot role is not for this depot");
+    }
+
+    if !(depot_role.borrow().permissions & ROLE_ADMIN!() == 0 || depot.borrow().owner == authority.key()) {
+        panic!("Only depot owner can remove admin role");
+    }
+}
+
+pub fn update_depot_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut name_array: [u16; 32],
+    mut info_array: [u16; 256],
+    mut coordinates_class: Coordinates,
+) -> () {
+    check_not_paused(&config);
+
+    if !(depot.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner of depot");
+    }
+
+    edit_depot(&depot, name_array, info_array, coordinates_class);
+}
+
+pub fn change_depot_owner_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_owner: Pubkey,
+) -> () {
+    check_not_paused(&config);
+
+    if !(depot.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner of depot");
+    }
+
+    set_depot_owner(&depot, new_owner);
+}
+
+pub fn create_multisig_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut creator: SeahorseSigner<'info, '_>,
+    mut multisig: Empty<Mutable<LoadedMultisig<'info, '_>>>,
+    mut owners: [Pubkey; 10],
+    mut owner_count: u8,
+    mut threshold: u8,
+    mut seed_random: u128,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_multisig_owners(&owners, owner_count, threshold);
+
+    let mut multisig = multisig.account.clone();
+
+    assign!(multisig.borrow_mut().owners, Mutable::<[Pubkey; 10]>::new(owners));
+
+    assign!(multisig.borrow_mut().owner_count, owner_count);
+
+    assign!(multisig.borrow_mut().threshold, threshold);
+
+    assign!(multisig.borrow_mut().time_created, time);
+}
+
+pub fn propose_depot_action_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut proposer: SeahorseSigner<'info, '_>,
+    mut multisig: Mutable<LoadedMultisig<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut proposal: Empty<Mutable<LoadedMultisigProposal<'info, '_>>>,
+    mut action: ProposalAction,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !(depot.borrow().owner == multisig.borrow().__account__.key()) {
+        panic!("Depot is not owned by multisig");
+    }
+
+    let proposer_index = match multisig_owner_index(&multisig, proposer.key()) {
+        Some(proposer_index) => proposer_index,
+        None => panic!("Proposer is not an owner of multisig"),
+    };
+
+    let mut proposal = proposal.account.clone();
+    let mut approvals = [false; 10];
+
+    approvals[proposer_index] = true;
+
+    assign!(proposal.borrow_mut().multisig, multisig.borrow().__account__.key());
+
+    assign!(proposal.borrow_mut().depot, depot.borrow().__account__.key());
+
+    assign!(proposal.borrow_mut().proposer, proposer.key());
+
+    assign!(proposal.borrow_mut().action, action);
+
+    assign!(proposal.borrow_mut().approvals, Mutable::<[bool; 10]>::new(approvals));
+
+    assign!(proposal.borrow_mut().approval_count, 1);
+
+    assign!(proposal.borrow_mut().depot_owner_epoch, depot.borrow().owner_epoch);
+
+    assign!(proposal.borrow_mut().multisig_owners_epoch, multisig.borrow().owners_epoch);
+
+    assign!(proposal.borrow_mut().time_created, time);
+
+    let proposal_count = multisig.borrow().proposal_count + 1;
+
+    assign!(multisig.borrow_mut().proposal_count, proposal_count);
+}
+
+pub fn approve_multisig_proposal_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut multisig: Mutable<LoadedMultisig<'info, '_>>,
+    mut proposal: Mutable<LoadedMultisigProposal<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !(proposal.borrow().multisig == multisig.borrow().__account__.key()) {
+        panic!("Proposal is not for this multisig");
+    }
+
+    if proposal.borrow().executed {
+        panic!("Proposal is already executed");
+    }
+
+    if !(proposal.borrow().multisig_owners_epoch == multisig.borrow().owners_epoch) {
+        panic!("Proposal was made under a previous multisig owner set");
+    }
+
+    let owner_index = match multisig_owner_index(&multisig, owner.key()) {
+        Some(owner_index) => owner_index,
+        None => panic!("Owner is not an owner of multisig"),
+    };
+
+    if proposal.borrow().approvals.borrow()[owner_index] {
+        panic!("Owner has already approved proposal");
+    }
+
+    index_assign!(proposal.borrow_mut().approvals.borrow_mut(), owner_index, true);
+
+    let approval_count = proposal.borrow().approval_count + 1;
+
+    assign!(proposal.borrow_mut().approval_count, approval_count);
+}
+
+pub fn execute_depot_proposal_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut multisig: Mutable<LoadedMultisig<'info, '_>>,
+    mut proposal: Mutable<LoadedMultisigProposal<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_proposal_ready(&multisig, &proposal, &depot);
+
+    let action = proposal.borrow().action.clone();
+
+    match action {
+        ProposalAction::EditDepot {
+            name_array,
+            info_array,
+            coordinates_class,
+        } => edit_depot(&depot, name_array, info_array, coordinates_class),
+        ProposalAction::ChangeDepotOwner { new_owner } => {
+            set_depot_owner(&depot, new_owner);
+        }
+        ProposalAction::SetOwners {
+            owners,
+            owner_count,
+            threshold,
+        } => {
+            check_multisig_owners(&owners, owner_count, threshold);
+
+            assign!(multisig.borrow_mut().owners, Mutable::<[Pubkey; 10]>::new(owners));
+
+            assign!(multisig.borrow_mut().owner_count, owner_count);
+
+            assign!(multisig.borrow_mut().threshold, threshold);
+
+            // Pending proposals were approved by the previous owner set
+            let owners_epoch = multisig.borrow().owners_epoch + 1;
+
+            assign!(multisig.borrow_mut().owners_epoch, owners_epoch);
+        }
+        ProposalAction::AcceptTransfer { .. } => {
+            panic!("Accept transfer proposals are executed by transfer_items_to_multisig_depot");
+        }
+        ProposalAction::DispatchTransfer { .. } => {
+            panic!("Dispatch transfer proposals are executed by transfer_items_from_multisig_depot");
+        }
+        ProposalAction::GrantRole { .. } => {
+            panic!("Grant role proposals are executed by grant_depot_role_by_multisig");
+        }
+        ProposalAction::RevokeRole { .. } => {
+            panic!("Revoke role proposals are executed by revoke_depot_role_by_multisig");
+        }
+    }
+
+    assign!(proposal.borrow_mut().executed, true);
+
+    assign!(proposal.borrow_mut().time_executed, time);
+}
+
+pub fn transfer_items_to_multisig_depot_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Mutable<LoadedTransferRules<'info, '_>>,
+    mut old_depot_owner: SeahorseSigner<'info, '_>,
+    mut old_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut old_depot_owner_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut old_depot_owner_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut organization: Option<Mutable<LoadedOrganization<'info, '_>>>,
+    mut multisig: Mutable<LoadedMultisig<'info, '_>>,
+    mut proposal: Mutable<LoadedMultisigProposal<'info, '_>>,
+    mut new_depot_accreditation_authority: Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut override_compliance: bool,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &old_depot,
+        old_depot_owner.key(),
+        &old_depot_owner_role,
+        &old_depot_owner_hub_depot,
+        ROLE_SHIPPER!(),
+    ) {
+        panic!("Old depot owner is not the owner or a shipper of old depot");
+    }
+
+    check_proposal_ready(&multisig, &proposal, &new_depot);
+
+    let expected_action = ProposalAction::AcceptTransfer {
+        items: items.borrow().__account__.key(),
+        old_depot: old_depot.borrow().__account__.key(),
+    };
+
+    if !(proposal.borrow().action == expected_action) {
+        panic!("Proposal does not accept this transfer");
+    }
+
+    move_items_to_depot(
+        time,
+        time,
+        &old_depot,
+        &new_depot,
+        &transfer_rules,
+        &organization,
+        &new_depot_accreditation_authority,
+        &items,
+        override_compliance,
+        true,
+    );
+
+    assign!(proposal.borrow_mut().executed, true);
+
+    assign!(proposal.borrow_mut().time_executed, time);
+}
+
+pub fn transfer_items_from_multisig_depot_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Mutable<LoadedTransferRules<'info, '_>>,
+    mut new_depot_owner: Option<SeahorseSigner<'info, '_>>,
+    mut old_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_depot_owner_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut new_depot_owner_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut organization: Option<Mutable<LoadedOrganization<'info, '_>>>,
+    mut partner_agreement: Option<Mutable<LoadedPartnerAgreement<'info, '_>>>,
+    mut multisig: Mutable<LoadedMultisig<'info, '_>>,
+    mut proposal: Mutable<LoadedMultisigProposal<'info, '_>>,
+    mut new_depot_accreditation_authority: Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    // The approved proposal stands in for the old depot owner's signature
+    check_proposal_ready(&multisig, &proposal, &old_depot);
+
+    let override_compliance = match proposal.borrow().action.clone() {
+        ProposalAction::DispatchTransfer {
+            items: proposal_items,
+            new_depot: proposal_new_depot,
+            override_compliance,
+        } => {
+            if !(proposal_items == items.borrow().__account__.key()
+                && proposal_new_depot == new_depot.borrow().__account__.key())
+            {
+                panic!("Proposal does not dispatch this transfer");
+            }
+
+            override_compliance
+        }
+        _ => panic!("Proposal does not dispatch this transfer"),
+    };
+
+    let new_depot_owner_signed = check_new_depot_accepts(
+        time,
+        &new_depot_owner,
+        &old_depot,
+        &new_depot,
+        &new_depot_owner_role,
+        &new_depot_owner_hub_depot,
+        &organization,
+        &partner_agreement,
+        &items,
+    );
+
+    move_items_to_depot(
+        time,
+        time,
+        &old_depot,
+        &new_depot,
+        &transfer_rules,
+        &organization,
+        &new_depot_accreditation_authority,
+        &items,
+        override_compliance,
+        new_depot_owner_signed,
+    );
+
+    assign!(proposal.borrow_mut().executed, true);
+
+    assign!(proposal.borrow_mut().time_executed, time);
+}
+
+pub fn grant_depot_role_by_multisig_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut multisig: Mutable<LoadedMultisig<'info, '_>>,
+    mut proposal: Mutable<LoadedMultisigProposal<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut depot_role: Empty<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut delegate: Pubkey,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_proposal_ready(&multisig, &proposal, &depot);
+
+    let permissions = match proposal.borrow().action.clone() {
+        ProposalAction::GrantRole {
+            delegate: proposal_delegate,
+            permissions,
+        } => {
+            if !(proposal_delegate == delegate) {
+                panic!("Proposal does not grant a role to this delegate");
+            }
+
+            permissions
+        }
+        _ => panic!("Proposal does not grant a role"),
+    };
+
+    check_role_permissions(permissions);
+
+    let mut depot_role = depot_role.account.clone();
+
+    grant_depot_role(
+        &depot,
+        &depot_role,
+        delegate,
+        permissions,
+        multisig.borrow().__account__.key(),
+        time,
+    );
+
+    assign!(proposal.borrow_mut().executed, true);
+
+    assign!(proposal.borrow_mut().time_executed, time);
+}
+
+pub fn revoke_depot_role_by_multisig_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut multisig: Mutable<LoadedMultisig<'info, '_>>,
+    mut proposal: Mutable<LoadedMultisigProposal<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut depot_role: Mutable<LoadedDepotRole<'info, '_>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_proposal_ready(&multisig, &proposal, &depot);
+
+    match proposal.borrow().action.clone() {
+        ProposalAction::RevokeRole { delegate } => {
+            if !(depot_role.borrow().delegate == delegate) {
+                panic!("Proposal does not revoke this delegate's role");
+            }
+        }
+        _ => panic!("Proposal does not revoke a role"),
+    }
+
+    if !(depot_role.borrow().depot == depot.borrow().__account__.key()) {
+        panic!("Depot role is not for this depot");
+    }
+
+    assign!(proposal.borrow_mut().executed, true);
+
+    assign!(proposal.borrow_mut().time_executed, time);
+}
+
+pub fn create_organization_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut creator: SeahorseSigner<'info, '_>,
+    mut organization: Empty<Mutable<LoadedOrganization<'info, '_>>>,
+    mut name_array: [u16; 32],
+    mut seed_random: u128,
+) -> () {
+    check_not_paused(&config);
+
+    let mut time = clock.unix_timestamp;
+    let mut organization = organization.account.clone();
+    let mut admins = [Pubkey::default(); 8];
+
+    admins[0] = creator.key();
+
+    assign!(organization.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(organization.borrow_mut().admins, Mutable::<[Pubkey; 8]>::new(admins));
+
+    assign!(organization.borrow_mut().admin_count, 1);
+
+    assign!(organization.borrow_mut().time_created, time);
+}
+
+pub fn add_organization_admin_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut organization: Mutable<LoadedOrganization<'info, '_>>,
+    mut new_admin: Pubkey,
+) -> () {
+    check_not_paused(&config);
+
+    if organization_admin_index(&organization, admin.key()).is_none() {
+        panic!("Admin is not an admin of organization");
+    }
+
+    if organization_admin_index(&organization, new_admin).is_some() {
+        panic!("New admin is already an admin of organization");
+    }
+
+    let admin_count = organization.borrow().admin_count;
+
+    if !((admin_count as usize) < ORGANIZATION_MAX_ADMINS!()) {
+        panic!("Organization admin list is full");
+    }
+
+    index_assign!(organization.borrow_mut().admins.borrow_mut(), admin_count as usize, new_admin);
+
+    assign!(organization.borrow_mut().admin_count, admin_count + 1);
+}
+
+pub fn remove_organization_admin_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut organization: Mutable<LoadedOrganization<'info, '_>>,
+    mut removed_admin: Pubkey,
+) -> () {
+    check_not_paused(&config);
+
+    if organization_admin_index(&organization, admin.key()).is_none() {
+        panic!("Admin is not an admin of organization");
+    }
+
+    let removed_index = match organization_admin_index(&organization, removed_admin) {
+        Some(removed_index) => removed_index,
+        None => panic!("Removed admin is not an admin of organization"),
+    };
+
+    let admin_count = organization.borrow().admin_count;
+
+    if !(admin_count > 1) {
+        panic!("Organization must keep at least one admin");
+    }
+
+    let last_admin = organization.borrow().admins.borrow()[admin_count as usize - 1];
+
+    index_assign!(organization.borrow_mut().admins.borrow_mut(), removed_index, last_admin);
+
+    index_assign!(organization.borrow_mut().admins.borrow_mut(), admin_count as usize - 1, Pubkey::default());
+
+    assign!(organization.borrow_mut().admin_count, admin_count - 1);
+}
+
+pub fn set_organization_policy_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut organization: Mutable<LoadedOrganization<'info, '_>>,
+    mut single_signer_internal_transfers: bool,
+    mut require_verified_destination: bool,
+) -> () {
+    check_not_paused(&config);
+
+    if organization_admin_index(&organization, admin.key()).is_none() {
+        panic!("Admin is not an admin of organization");
+    }
+
+    assign!(organization.borrow_mut().single_signer_internal_transfers, single_signer_internal_transfers);
+
+    assign!(organization.borrow_mut().require_verified_destination, require_verified_destination);
+}
+
+pub fn add_depot_to_organization_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut depot_owner: SeahorseSigner<'info, '_>,
+    mut organization: Mutable<LoadedOrganization<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut organization_depot: Empty<Mutable<LoadedOrganizationDepot<'info, '_>>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if organization_admin_index(&organization, admin.key()).is_none() {
+        panic!("Admin is not an admin of organization");
+    }
+
+    if !(depot.borrow().owner == depot_owner.key()) {
+        panic!("Depot owner is not the owner of depot");
+    }
+
+    if !(depot.borrow().organization == Pubkey::default()) {
+        panic!("Depot already belongs to an organization");
+    }
+
+    let index = organization.borrow().depot_count;
+    let mut organization_depot = organization_depot.account.clone();
+
+    assign!(organization_depot.borrow_mut().organization, organization.borrow().__account__.key());
+
+    assign!(organization_depot.borrow_mut().depot, depot.borrow().__account__.key());
+
+    assign!(organization_depot.borrow_mut().index, index);
+
+    assign!(organization_depot.borrow_mut().time_created, time);
+
+    assign!(depot.borrow_mut().organization, organization.borrow().__account__.key());
+
+    assign!(depot.borrow_mut().organization_index, index);
+
+    assign!(depot.borrow_mut().organization_owner_epoch, depot.borrow().owner_epoch);
+
+    assign!(organization.borrow_mut().depot_count, index + 1);
+}
+
+pub fn remove_depot_from_organization_handler<'info>(
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut organization: Mutable<LoadedOrganization<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut last_organization_depot: Mutable<LoadedOrganizationDepot<'info, '_>>,
+    mut organization_depot: Option<Mutable<LoadedOrganizationDepot<'info, '_>>>,
+    mut last_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+) -> () {
+    check_not_paused(&config);
+
+    let organization_key = organization.borrow().__account__.key();
+
+    if !(organization_admin_index(&organization, authority.key()).is_some()
+        || depot.borrow().owner == authority.key())
+    {
+        panic!("Authority is not an admin of organization or the owner of depot");
+    }
+
+    if !(depot.borrow().organization == organization_key) {
+        panic!("Depot does not belong to organization");
+    }
+
+    let depot_count = organization.borrow().depot_count;
+
+    if !(last_organization_depot.borrow().organization == organization_key
+        && last_organization_depot.borrow().index == depot_count - 1)
+    {
+        panic!("Last organization depot is not the last entry of organization");
+    }
+
+    // Swap the last entry into the removed depot's slot so indexes stay contiguous
+    if last_organization_depot.borrow().depot != depot.borrow().__account__.key() {
+        let organization_depot = match organization_depot {
+            Some(organization_depot) => organization_depot,
+            None => panic!("Organization depot entry is required"),
+        };
+
+        let last_depot = match last_depot {
+            Some(last_depot) => last_depot,
+            None => panic!("Last depot is required"),
+        };
+
+        if !(organization_depot.borrow().organization == organization_key
+            && organization_depot.borrow().depot == depot.borrow().__account__.key())
+        {
+            panic!("Organization depot entry is not for this depot");
+        }
+
+        if !(last_depot.borrow().__account__.key() == last_organization_depot.borrow().depot) {
+            panic!("Last depot is not the depot of the last entry");
+        }
+
+        let index = organization_depot.borrow().index;
+
+        assign!(organization_depot.borrow_mut().depot, last_depot.borrow().__account__.key());
+
+        assign!(last_depot.borrow_mut().organization_index, index);
+    }
+
+    assign!(depot.borrow_mut().organization, Pubkey::default());
+
+    assign!(depot.borrow_mut().organization_index, 0);
+
+    assign!(organization.borrow_mut().depot_count, depot_count - 1);
+}
+
+pub fn init_config_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Empty<Mutable<LoadedConfig<'info, '_>>>,
+    mut max_speed: f64,
+    mut depot_fee: u64,
+    mut items_fee: u64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    if !(max_speed >= 0.0) {
+        panic!("Max speed is negative");
+    }
+
+    let mut config = config.account.clone();
+
+    assign!(config.borrow_mut().admin, admin.key());
+
+    assign!(config.borrow_mut().max_speed, max_speed);
+
+    assign!(config.borrow_mut().depot_fee, depot_fee);
+
+    assign!(config.borrow_mut().items_fee, items_fee);
+
+    assign!(config.borrow_mut().time_created, time);
+}
+
+pub fn set_paused_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut paused: bool,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    assign!(config.borrow_mut().paused, paused);
+}
+
+pub fn update_config_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut max_speed: f64,
+    mut depot_fee: u64,
+    mut items_fee: u64,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    if !(max_speed >= 0.0) {
+        panic!("Max speed is negative");
+    }
+
+    assign!(config.borrow_mut().max_speed, max_speed);
+
+    assign!(config.borrow_mut().depot_fee, depot_fee);
+
+    assign!(config.borrow_mut().items_fee, items_fee);
+}
+
+pub fn propose_config_admin_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut new_admin: Pubkey,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    assign!(config.borrow_mut().pending_admin, new_admin);
+}
+
+pub fn accept_config_admin_handler<'info>(
+    mut new_admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+) -> () {
+    if !(config.borrow().pending_admin != Pubkey::default()
+        && config.borrow().pending_admin == new_admin.key())
+    {
+        panic!("Signer is not the pending config admin");
+    }
+
+    assign!(config.borrow_mut().admin, new_admin.key());
+
+    assign!(config.borrow_mut().pending_admin, Pubkey::default());
+}
+
+pub fn withdraw_fees_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut receiver: AccountInfo<'info>,
+    mut amount: u64,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    let config_info = config.borrow().__account__.to_account_info();
+    let rent_minimum = Rent::get().unwrap().minimum_balance(config_info.data_len());
+
+    if !(config_info.lamports() >= rent_minimum.checked_add(amount).unwrap()) {
+        panic!("Withdraw amount exceeds collected fees");
+    }
+
+    **config_info.try_borrow_mut_lamports().unwrap() -= amount;
+
+    **receiver.try_borrow_mut_lamports().unwrap() += amount;
+}
+
+pub fn register_accreditation_authority_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut accreditation_authority: Empty<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    mut authority: Pubkey,
+    mut name_array: [u16; 32],
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_config_admin(&config, admin.key());
+
+    let mut accreditation_authority = accreditation_authority.account.clone();
+
+    assign!(accreditation_authority.borrow_mut().authority, authority);
+
+    assign!(accreditation_authority.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(accreditation_authority.borrow_mut().active, true);
+
+    assign!(accreditation_authority.borrow_mut().time_created, time);
+}
+
+pub fn set_accreditation_authority_active_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut accreditation_authority: Mutable<LoadedAccreditationAuthority<'info, '_>>,
+    mut active: bool,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    assign!(accreditation_authority.borrow_mut().active, active);
+}
+
+pub fn issue_depot_verification_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut accreditation_authority: Mutable<LoadedAccreditationAuthority<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut verification_expiry: i64,
+    mut credential_ref: [u8; 32],
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !(accreditation_authority.borrow().authority == authority.key()) {
+        panic!("Authority is not the accreditation authority");
+    }
+
+    if !accreditation_authority.borrow().active {
+        panic!("Accreditation authority is not active");
+    }
+
+    if !(verification_expiry == 0 || verification_expiry > time) {
+        panic!("Verification expiry is in the past");
+    }
+
+    assign!(depot.borrow_mut().verified_by, authority.key());
+
+    assign!(depot.borrow_mut().verification_time, time);
+
+    assign!(depot.borrow_mut().verification_expiry, verification_expiry);
+
+    assign!(depot.borrow_mut().credential_ref, Mutable::<[u8; 32]>::new(credential_ref));
+
+    assign!(depot.borrow_mut().verification_revoked, false);
+}
+
+pub fn revoke_depot_verification_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut accreditation_authority: Mutable<LoadedAccreditationAuthority<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !(accreditation_authority.borrow().authority == authority.key()) {
+        panic!("Authority is not the accreditation authority");
+    }
+
+    if !(depot.borrow().verified_by == authority.key()) {
+        panic!("Depot verification was not issued by this authority");
+    }
+
+    assign!(depot.borrow_mut().verification_revoked, true);
+}
+
+pub fn set_items_verification_policy_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut require_verified_destination: bool,
+) -> () {
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner");
+    }
+
+    assign!(items.borrow_mut().require_verified_destination, require_verified_destination);
+}
+
+pub fn change_items_owner_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut new_owner: Pubkey,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner");
+    }
+
+    check_items_not_frozen(&items);
+
+    assign!(items.borrow_mut().owner, new_owner);
+
+    assign!(items.borrow_mut().cur_time, time);
+}
+
+pub fn register_regulator_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut regulator_account: Empty<Mutable<LoadedRegulator<'info, '_>>>,
+    mut regulator: Pubkey,
+    mut name_array: [u16; 32],
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_config_admin(&config, admin.key());
+
+    let mut regulator_account = regulator_account.account.clone();
+
+    assign!(regulator_account.borrow_mut().regulator, regulator);
+
+    assign!(regulator_account.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(regulator_account.borrow_mut().active, true);
+
+    assign!(regulator_account.borrow_mut().time_created, time);
+}
+
+pub fn set_regulator_active_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut regulator_account: Mutable<LoadedRegulator<'info, '_>>,
+    mut active: bool,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    assign!(regulator_account.borrow_mut().active, active);
+}
+
+pub fn record_freeze<'info>(
+    time: i64,
+    regulator: Pubkey,
+    items: &Mutable<LoadedItems<'info, '_>>,
+    freeze_record: Empty<Mutable<LoadedFreezeRecord<'info, '_>>>,
+    frozen: bool,
+    reason_code: u16,
+) -> () {
+    let mut freeze_record = freeze_record.account.clone();
+
+    assign!(freeze_record.borrow_mut().items, items.borrow().__account__.key());
+
+    assign!(freeze_record.borrow_mut().regulator, regulator);
+
+    assign!(freeze_record.borrow_mut().frozen, frozen);
+
+    assign!(freeze_record.borrow_mut().reason_code, reason_code);
+
+    assign!(freeze_record.borrow_mut().time, time);
+
+    let freeze_record_count = items.borrow().freeze_record_count + 1;
+
+    assign!(items.borrow_mut().freeze_record_count, freeze_record_count);
+}
+
+pub fn freeze_items_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut regulator: SeahorseSigner<'info, '_>,
+    mut regulator_account: Mutable<LoadedRegulator<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut freeze_record: Empty<Mutable<LoadedFreezeRecord<'info, '_>>>,
+    mut reason_code: u16,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_active_regulator(&regulator_account, regulator.key());
+
+    if items.borrow().frozen {
+        panic!("Items are already frozen");
+    }
+
+    record_freeze(time, regulator.key(), &items, freeze_record, true, reason_code);
+
+    assign!(items.borrow_mut().frozen, true);
+
+    assign!(items.borrow_mut().freeze_reason, reason_code);
+
+    assign!(items.borrow_mut().frozen_by, regulator.key());
+
+    assign!(items.borrow_mut().frozen_at, time);
+}
+
+pub fn unfreeze_items_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut regulator: SeahorseSigner<'info, '_>,
+    mut regulator_account: Mutable<LoadedRegulator<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut freeze_record: Empty<Mutable<LoadedFreezeRecord<'info, '_>>>,
+    mut reason_code: u16,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_active_regulator(&regulator_account, regulator.key());
+
+    if !items.borrow().frozen {
+        panic!("Items are not frozen");
+    }
+
+    record_freeze(time, regulator.key(), &items, freeze_record, false, reason_code);
+
+    assign!(items.borrow_mut().frozen, false);
+
+    assign!(items.borrow_mut().freeze_reason, reason_code);
+
+    assign!(items.borrow_mut().frozen_by, regulator.key());
+
+    assign!(items.borrow_mut().frozen_at, time);
+}
+
+pub fn register_inspector_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut inspector_account: Empty<Mutable<LoadedInspector<'info, '_>>>,
+    mut inspector: Pubkey,
+    mut name_array: [u16; 32],
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_config_admin(&config, admin.key());
+
+    let mut inspector_account = inspector_account.account.clone();
+
+    assign!(inspector_account.borrow_mut().inspector, inspector);
+
+    assign!(inspector_account.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(inspector_account.borrow_mut().active, true);
+
+    assign!(inspector_account.borrow_mut().time_created, time);
+}
+
+pub fn set_inspector_active_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut inspector_account: Mutable<LoadedInspector<'info, '_>>,
+    mut active: bool,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    assign!(inspector_account.borrow_mut().active, active);
+}
+
+pub fn inspect_items_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut inspector: SeahorseSigner<'info, '_>,
+    mut inspector_account: Mutable<LoadedInspector<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut inspection: Empty<Mutable<LoadedInspection<'info, '_>>>,
+    mut verdict: InspectionVerdict,
+    mut findings_hash: [u8; 32],
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(inspector_account.borrow().inspector == inspector.key()) {
+        panic!("Signer is not the inspector");
+    }
+
+    if !inspector_account.borrow().active {
+        panic!("Inspector is not active");
+    }
+
+    let depot = items.borrow().depot;
+    let mut inspection = inspection.account.clone();
+
+    assign!(inspection.borrow_mut().items, items.borrow().__account__.key());
+
+    assign!(inspection.borrow_mut().depot, depot);
+
+    assign!(inspection.borrow_mut().inspector, inspector.key());
+
+    assign!(inspection.borrow_mut().verdict, verdict);
+
+    assign!(inspection.borrow_mut().findings_hash, Mutable::<[u8; 32]>::new(findings_hash));
+
+    assign!(inspection.borrow_mut().time, time);
+
+    let inspection_count = items.borrow().inspection_count + 1;
+
+    assign!(items.borrow_mut().inspection_count, inspection_count);
+
+    assign!(items.borrow_mut().last_inspection_verdict, verdict);
+
+    assign!(items.borrow_mut().last_inspection_depot, depot);
+
+    assign!(items.borrow_mut().last_inspection_time, time);
+}
+
+pub fn set_depot_inspection_policy_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut require_inspection_before_dispatch: bool,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    assign!(depot.borrow_mut().require_inspection_before_dispatch, require_inspection_before_dispatch);
+}
+
+pub fn register_certifier_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut certifier_account: Empty<Mutable<LoadedCertifier<'info, '_>>>,
+    mut certifier: Pubkey,
+    mut name_array: [u16; 32],
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_config_admin(&config, admin.key());
+
+    let mut certifier_account = certifier_account.account.clone();
+
+    assign!(certifier_account.borrow_mut().certifier, certifier);
+
+    assign!(certifier_account.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(certifier_account.borrow_mut().active, true);
+
+    assign!(certifier_account.borrow_mut().time_created, time);
+}
+
+pub fn set_certifier_active_handler<'info>(
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut certifier_account: Mutable<LoadedCertifier<'info, '_>>,
+    mut active: bool,
+) -> () {
+    check_config_admin(&config, admin.key());
+
+    assign!(certifier_account.borrow_mut().active, active);
+}
+
+pub fn certify_items_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut certifier: SeahorseSigner<'info, '_>,
+    mut certifier_account: Mutable<LoadedCertifier<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut certification: Empty<Mutable<LoadedCertification<'info, '_>>>,
+    mut scheme: [u8; 16],
+    mut valid_from: i64,
+    mut valid_until: i64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    issue_certification(
+        time,
+        &certifier,
+        &certifier_account,
+        &certification,
+        items.borrow().__account__.key(),
+        false,
+        scheme,
+        valid_from,
+        valid_until,
+    );
+}
+
+pub fn certify_depot_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut certifier: SeahorseSigner<'info, '_>,
+    mut certifier_account: Mutable<LoadedCertifier<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut certification: Empty<Mutable<LoadedCertification<'info, '_>>>,
+    mut scheme: [u8; 16],
+    mut valid_from: i64,
+    mut valid_until: i64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    issue_certification(
+        time,
+        &certifier,
+        &certifier_account,
+        &certification,
+        depot.borrow().__account__.key(),
+        true,
+        scheme,
+        valid_from,
+        valid_until,
+    );
+}
+
+pub fn revoke_certification_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut certifier: SeahorseSigner<'info, '_>,
+    mut certification: Mutable<LoadedCertification<'info, '_>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !(certification.borrow().certifier == certifier.key()) {
+        panic!("Signer is not the certifier of this certification");
+    }
+
+    if certification.borrow().revoked {
+        panic!("Certification is already revoked");
+    }
+
+    assign!(certification.borrow_mut().revoked, true);
+
+    assign!(certification.borrow_mut().revoked_at, time);
+}
+
+pub fn renew_certification_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut certifier: SeahorseSigner<'info, '_>,
+    mut certifier_account: Mutable<LoadedCertifier<'info, '_>>,
+    mut certification: Mutable<LoadedCertification<'info, '_>>,
+    mut valid_from: i64,
+    mut valid_until: i64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_certification_window(time, &certifier, &certifier_account, valid_from, valid_until);
+
+    if !(certification.borrow().certifier == certifier.key()) {
+        panic!("Signer is not the certifier of this certification");
+    }
+
+    // Certifications live at a fixed address per subject, scheme and certifier,
+    // so renewal reissues them in place, lifting any earlier revocation
+    assign!(certification.borrow_mut().valid_from, valid_from);
+
+    assign!(certification.borrow_mut().valid_until, valid_until);
+
+    assign!(certification.borrow_mut().revoked, false);
+
+    assign!(certification.borrow_mut().revoked_at, 0);
+
+    assign!(certification.borrow_mut().renewed_at, time);
+}
+
+pub fn has_certification_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut certification: Mutable<LoadedCertification<'info, '_>>,
+    mut certifier_account: Mutable<LoadedCertifier<'info, '_>>,
+    mut scheme: [u8; 16],
+) -> bool {
+    let mut time = clock.unix_timestamp;
+    let certification = certification.borrow();
+
+    // A depot certification covers the items it produced
+    let subject = if certification.subject_is_depot {
+        items.borrow().origin_depot
+    } else {
+        items.borrow().__account__.key()
+    };
+
+    if !(certifier_account.borrow().certifier == certification.certifier) {
+        panic!("Certifier account does not match certification");
+    }
+
+    return certification.subject == subject
+        && *certification.scheme.borrow() == scheme
+        && !certification.revoked
+        && certifier_account.borrow().active
+        && certification.valid_from <= time
+        && time < certification.valid_until;
+}
+
+pub fn attach_document_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut signer: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut signer_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut signer_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut document: Empty<Mutable<LoadedDocument<'info, '_>>>,
+    mut document_type: DocumentType,
+    mut hash: [u8; 32],
+    mut uri_array: Option<[u16; 128]>,
+    mut for_transfer: bool,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().depot == depot.borrow().__account__.key()) {
+        panic!("Depot is not same as items depot");
+    }
+
+    if !(items.borrow().owner == signer.key()
+        || depot_authorized(&depot, signer.key(), &signer_role, &signer_hub_depot, ROLE_SHIPPER!()))
+    {
+        panic!("Signer is not the items owner or a shipper of items depot");
+    }
+
+    let mut document = document.account.clone();
+
+    assign!(document.borrow_mut().items, items.borrow().__account__.key());
+
+    assign!(document.borrow_mut().document_type, document_type);
+
+    assign!(document.borrow_mut().hash, Mutable::<[u8; 32]>::new(hash));
+
+    if let Some(uri_array) = uri_array {
+        assign!(document.borrow_mut().has_uri, true);
+
+        assign!(document.borrow_mut().uri_array, Mutable::<[u16; 128]>::new(uri_array));
+    }
+
+    assign!(document.borrow_mut().signer, signer.key());
+
+    assign!(document.borrow_mut().for_transfer, for_transfer);
+
+    assign!(document.borrow_mut().transfer_index, items.borrow().transfer_count);
+
+    assign!(document.borrow_mut().time, time);
+
+    let document_count = items.borrow().document_count + 1;
+
+    assign!(items.borrow_mut().document_count, document_count);
+
+    if for_transfer {
+        let pending_document_types =
+            items.borrow().pending_document_types | document_type_bit(document_type);
+
+        assign!(items.borrow_mut().pending_document_types, pending_document_types);
+    }
+}
+
+pub fn set_depot_document_policy_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut boundary_required_documents: u8,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    assign!(depot.borrow_mut().boundary_required_documents, boundary_required_documents);
+}
+
+pub fn init_transfer_rules_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Empty<Mutable<LoadedTransferRules<'info, '_>>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_config_admin(&config, admin.key());
+
+    let mut transfer_rules = transfer_rules.account.clone();
+
+    assign!(transfer_rules.borrow_mut().time_created, time);
+
+    assign!(transfer_rules.borrow_mut().last_update_time, time);
+}
+
+pub fn add_transfer_rule_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Mutable<LoadedTransferRules<'info, '_>>,
+    mut transfer_rule_class: TransferRule,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_config_admin(&config, admin.key());
+
+    let rule_count = transfer_rules.borrow().rule_count;
+
+    if !((rule_count as u64) < TRANSFER_RULES_MAX!()) {
+        panic!("Transfer rules are full");
+    }
+
+    if !(transfer_rule_class.hs_prefix_digits <= HS_CODE_DIGITS!()) {
+        panic!("HS prefix has too many digits");
+    }
+
+    index_assign!(transfer_rules.borrow_mut().rules.borrow_mut(), rule_count as usize, transfer_rule_class);
+
+    assign!(transfer_rules.borrow_mut().rule_count, rule_count + 1);
+
+    assign!(transfer_rules.borrow_mut().last_update_time, time);
+}
+
+pub fn remove_transfer_rule_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut admin: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Mutable<LoadedTransferRules<'info, '_>>,
+    mut index: u8,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_config_admin(&config, admin.key());
+
+    let last = transfer_rules.borrow().rule_count;
+
+    if !(index < last) {
+        panic!("Transfer rule index is out of range");
+    }
+
+    let last = last - 1;
+    let last_rule = transfer_rules.borrow().rules.borrow()[last as usize];
+
+    index_assign!(transfer_rules.borrow_mut().rules.borrow_mut(), index as usize, last_rule);
+
+    index_assign!(transfer_rules.borrow_mut().rules.borrow_mut(), last as usize, TransferRule::default());
+
+    assign!(transfer_rules.borrow_mut().rule_count, last);
+
+    assign!(transfer_rules.borrow_mut().last_update_time, time);
+}
+
+pub fn set_depot_jurisdiction_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut jurisdiction_code: [u8; 6],
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    assign!(depot.borrow_mut().jurisdiction_code, Mutable::<[u8; 6]>::new(jurisdiction_code));
+}
+
+pub fn set_depot_hazmat_licenses_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut accreditation_authority: Mutable<LoadedAccreditationAuthority<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut licensed_hazmat_classes: u16,
+) -> () {
+    check_not_paused(&config);
+
+    if !(accreditation_authority.borrow().authority == authority.key()) {
+        panic!("Authority is not the accreditation authority");
+    }
+
+    if !accreditation_authority.borrow().active {
+        panic!("Accreditation authority is not active");
+    }
+
+    // Bit n licenses UN hazmat class n, bit 0 is unused
+    if !(licensed_hazmat_classes >> (HAZMAT_CLASS_MAX!() + 1) == 0 && licensed_hazmat_classes & 1 == 0) {
+        panic!("Licensed hazmat classes contain an invalid class");
+    }
+
+    assign!(depot.borrow_mut().licensed_hazmat_classes, licensed_hazmat_classes);
+
+    assign!(depot.borrow_mut().hazmat_licensed_by, authority.key());
+}
+
+pub fn set_depot_capacity_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut max_item_count: u64,
+    mut max_weight_grams: u64,
+    mut max_volume_cm3: u64,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    assign!(depot.borrow_mut().max_item_count, max_item_count);
+
+    assign!(depot.borrow_mut().max_weight_grams, max_weight_grams);
+
+    assign!(depot.borrow_mut().max_volume_cm3, max_volume_cm3);
+}
+
+pub fn close_items_handler<'info>(
+    mut owner: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !(items.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner of items");
+    }
+
+    check_items_not_frozen(&items);
+
+    // Delivered items already left their last depot
+    if items.borrow().delivered {
+        return;
+    }
+
+    if !(items.borrow().depot == depot.borrow().__account__.key()) {
+        panic!("Depot is not same as items depot");
+    }
+
+    remove_items_from_depot(&depot, &items);
+}
+
+pub fn stocktake_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut auditor: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut auditor_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut auditor_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut stocktake_record: Empty<Mutable<LoadedStocktakeRecord<'info, '_>>>,
+    mut counted_item_count: u64,
+    mut commitment: [u8; 32],
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        auditor.key(),
+        &auditor_role,
+        &auditor_hub_depot,
+        ROLE_AUDITOR!(),
+    ) {
+        panic!("Auditor is not the owner or an auditor of depot");
+    }
+
+    let recorded_item_count = depot.borrow().item_count;
+    // Positive when more items were counted than the depot has on record
+    let discrepancy = i64::try_from(counted_item_count)
+        .unwrap()
+        .checked_sub(i64::try_from(recorded_item_count).unwrap())
+        .unwrap();
+
+    let mut stocktake_record = stocktake_record.account.clone();
+
+    assign!(stocktake_record.borrow_mut().depot, depot.borrow().__account__.key());
+
+    assign!(stocktake_record.borrow_mut().auditor, auditor.key());
+
+    assign!(stocktake_record.borrow_mut().counted_item_count, counted_item_count);
+
+    assign!(stocktake_record.borrow_mut().recorded_item_count, recorded_item_count);
+
+    assign!(stocktake_record.borrow_mut().discrepancy, discrepancy);
+
+    assign!(stocktake_record.borrow_mut().commitment, Mutable::<[u8; 32]>::new(commitment));
+
+    assign!(stocktake_record.borrow_mut().time, time);
+
+    let stocktake_count = depot.borrow().stocktake_count + 1;
+
+    assign!(depot.borrow_mut().stocktake_count, stocktake_count);
+
+    assign!(depot.borrow_mut().last_stocktake_time, time);
+
+    assign!(depot.borrow_mut().last_stocktake_discrepancy, discrepancy);
+}
+
+pub fn update_items_sub_location_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut staff: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut staff_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut staff_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut sub_location_class: SubLocation,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().depot == depot.borrow().__account__.key()) {
+        panic!("Depot is not same as items depot");
+    }
+
+    if !depot_authorized(
+        &depot,
+        staff.key(),
+        &staff_role,
+        &staff_hub_depot,
+        ROLE_RECEIVER!() | ROLE_SHIPPER!(),
+    ) {
+        panic!("Staff is not the owner or staff of items depot");
+    }
+
+    check_items_not_frozen(&items);
+
+    assign!(items.borrow_mut().sub_location_class, Mutable::<SubLocation>::new(sub_location_class));
+
+    assign!(items.borrow_mut().sub_location_updated_by, staff.key());
+
+    assign!(items.borrow_mut().sub_location_time, time);
+}
+
+pub fn request_parent_depot_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut parent_depot: Pubkey,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    if !(depot.borrow().parent_depot == Pubkey::default()) {
+        panic!("Depot already has a parent depot");
+    }
+
+    if !(parent_depot != depot.borrow().__account__.key()) {
+        panic!("Depot cannot be its own parent");
+    }
+
+    assign!(depot.borrow_mut().pending_parent_depot, parent_depot);
+}
+
+pub fn accept_child_depot_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut parent_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut child_depot: Mutable<LoadedDepot<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &parent_depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of parent depot");
+    }
+
+    if !(child_depot.borrow().pending_parent_depot == parent_depot.borrow().__account__.key()) {
+        panic!("Child depot has not requested this parent depot");
+    }
+
+    // Only depots without children of their own can join a hub, so the parent can
+    // never sit below the child and the hierarchy stays free of cycles
+    if !(child_depot.borrow().child_count == 0) {
+        panic!("Child depot has child depots of its own");
+    }
+
+    assign!(child_depot.borrow_mut().parent_depot, parent_depot.borrow().__account__.key());
+
+    assign!(child_depot.borrow_mut().pending_parent_depot, Pubkey::default());
+
+    assign!(child_depot.borrow_mut().parent_link_epoch, child_depot.borrow().owner_epoch);
+
+    // Nothing has been reported to the new parent yet
+    assign!(child_depot.borrow_mut().reported_item_count, 0);
+
+    assign!(child_depot.borrow_mut().reported_weight_grams, 0);
+
+    assign!(child_depot.borrow_mut().reported_volume_cm3, 0);
+
+    let child_count = parent_depot.borrow().child_count.checked_add(1).unwrap();
+
+    assign!(parent_depot.borrow_mut().child_count, child_count);
+
+    roll_up_depot(&child_depot, &parent_depot);
+}
+
+pub fn detach_child_depot_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut parent_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut child_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut child_authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut parent_authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut parent_authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !(child_depot.borrow().parent_depot == parent_depot.borrow().__account__.key()) {
+        panic!("Parent depot is not the parent of child depot");
+    }
+
+    // Either side of the link may end it; the parent is the hub the child's side answers to
+    if !(depot_authorized(
+        &child_depot,
+        authority.key(),
+        &child_authority_role,
+        &Some(parent_depot.clone()),
+        ROLE_ADMIN!(),
+    ) || depot_authorized(
+        &parent_depot,
+        authority.key(),
+        &parent_authority_role,
+        &parent_authority_hub_depot,
+        ROLE_ADMIN!(),
+    )) {
+        panic!("Authority is not the owner or an admin of either depot");
+    }
+
+    let children_item_count = parent_depot
+        .borrow()
+        .children_item_count
+        .checked_sub(child_depot.borrow().reported_item_count)
+        .unwrap();
+
+    let children_weight_grams = parent_depot
+        .borrow()
+        .children_weight_grams
+        .checked_sub(child_depot.borrow().reported_weight_grams)
+        .unwrap();
+
+    let children_volume_cm3 = parent_depot
+        .borrow()
+        .children_volume_cm3
+        .checked_sub(child_depot.borrow().reported_volume_cm3)
+        .unwrap();
+
+    let child_count = parent_depot.borrow().child_count.checked_sub(1).unwrap();
+
+    assign!(parent_depot.borrow_mut().children_item_count, children_item_count);
+
+    assign!(parent_depot.borrow_mut().children_weight_grams, children_weight_grams);
+
+    assign!(parent_depot.borrow_mut().children_volume_cm3, children_volume_cm3);
+
+    assign!(parent_depot.borrow_mut().child_count, child_count);
+
+    assign!(child_depot.borrow_mut().parent_depot, Pubkey::default());
+
+    assign!(child_depot.borrow_mut().parent_link_epoch, 0);
+
+    assign!(child_depot.borrow_mut().reported_item_count, 0);
+
+    assign!(child_depot.borrow_mut().reported_weight_grams, 0);
+
+    assign!(child_depot.borrow_mut().reported_volume_cm3, 0);
+}
+
+pub fn sync_depot_rollup_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut child_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut parent_depot: Mutable<LoadedDepot<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    // Permissionless, so indexers and keepers can refresh a hub after its children move stock
+    roll_up_depot(&child_depot, &parent_depot);
+}
+
+pub fn set_depot_type_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut depot_type: DepotType,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    assign!(depot.borrow_mut().depot_type, depot_type);
+}
+
+pub fn set_receiving_hours_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut authority: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut authority_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut authority_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut receiving_hours_class: Option<ReceivingHours>,
+    mut reject_after_hours: bool,
+) -> () {
+    check_not_paused(&config);
+
+    if !depot_authorized(
+        &depot,
+        authority.key(),
+        &authority_role,
+        &authority_hub_depot,
+        ROLE_ADMIN!(),
+    ) {
+        panic!("Authority is not the owner or an admin of depot");
+    }
+
+    match receiving_hours_class {
+        Some(receiving_hours_class) => {
+            if !(receiving_hours_class.utc_offset_minutes.abs() <= 14 * 60) {
+                panic!("UTC offset is out of range");
+            }
+
+            for day in 0..7 {
+                if !(receiving_hours_class.open_minutes[day] <= receiving_hours_class.close_minutes[day]
+                    && receiving_hours_class.close_minutes[day] <= 24 * 60)
+                {
+                    panic!("Receiving hours are not a valid window");
+                }
+            }
+
+            assign!(depot.borrow_mut().has_receiving_hours, true);
+
+            assign!(depot.borrow_mut().receiving_hours_class, Mutable::<ReceivingHours>::new(receiving_hours_class));
+        }
+        None => {
+            assign!(depot.borrow_mut().has_receiving_hours, false);
+
+            assign!(depot.borrow_mut().receiving_hours_class, Mutable::<ReceivingHours>::new(ReceivingHours::default()));
+        }
+    }
+
+    assign!(depot.borrow_mut().reject_after_hours, reject_after_hours);
+}
+
+pub fn create_partner_agreement_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut depot_a_owner: SeahorseSigner<'info, '_>,
+    mut depot_b_owner: SeahorseSigner<'info, '_>,
+    mut depot_a: Mutable<LoadedDepot<'info, '_>>,
+    mut depot_b: Mutable<LoadedDepot<'info, '_>>,
+    mut partner_agreement: Empty<Mutable<LoadedPartnerAgreement<'info, '_>>>,
+    mut allowed_hs_chapters: [u8; 13],
+    mut max_transfers_per_day: u32,
+    mut expiry_time: i64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    // Depots are ordered so each pair has a single agreement address
+    if !(depot_a.borrow().__account__.key() < depot_b.borrow().__account__.key()) {
+        panic!("Depot a must sort before depot b");
+    }
+
+    if !(depot_a.borrow().owner == depot_a_owner.key()) {
+        panic!("Depot a owner is not the owner of depot a");
+    }
+
+    if !(depot_b.borrow().owner == depot_b_owner.key()) {
+        panic!("Depot b owner is not the owner of depot b");
+    }
+
+    if !(expiry_time > time) {
+        panic!("Partner agreement expiry is in the past");
+    }
+
+    let mut partner_agreement = partner_agreement.account.clone();
+
+    assign!(partner_agreement.borrow_mut().depot_a, depot_a.borrow().__account__.key());
+
+    assign!(partner_agreement.borrow_mut().depot_b, depot_b.borrow().__account__.key());
+
+    assign!(partner_agreement.borrow_mut().allowed_hs_chapters, Mutable::<[u8; 13]>::new(allowed_hs_chapters));
+
+    assign!(partner_agreement.borrow_mut().max_transfers_per_day, max_transfers_per_day);
+
+    assign!(partner_agreement.borrow_mut().expiry_time, expiry_time);
+
+    assign!(partner_agreement.borrow_mut().depot_a_owner_epoch, depot_a.borrow().owner_epoch);
+
+    assign!(partner_agreement.borrow_mut().depot_b_owner_epoch, depot_b.borrow().owner_epoch);
+
+    assign!(partner_agreement.borrow_mut().time_created, time);
+}
+
+pub fn end_partner_agreement_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut depot: Mutable<LoadedDepot<'info, '_>>,
+    mut partner_agreement: Mutable<LoadedPartnerAgreement<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    let depot_key = depot.borrow().__account__.key();
+
+    if !(partner_agreement.borrow().depot_a == depot_key || partner_agreement.borrow().depot_b == depot_key) {
+        panic!("Depot is not party to partner agreement");
+    }
+
+    if !(depot.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner of depot");
+    }
+}
+
+pub fn create_carrier_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut carrier: Empty<Mutable<LoadedCarrier<'info, '_>>>,
+    mut name_array: [u16; 32],
+    mut seed_random: u128,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    let mut carrier = carrier.account.clone();
+
+    assign!(carrier.borrow_mut().owner, owner.key());
+
+    assign!(carrier.borrow_mut().name_array, Mutable::<[u16; 32]>::new(name_array));
+
+    assign!(carrier.borrow_mut().time_created, time);
+}
+
+pub fn register_carrier_device_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut carrier: Mutable<LoadedCarrier<'info, '_>>,
+    mut carrier_device: Empty<Mutable<LoadedCarrierDevice<'info, '_>>>,
+    mut device: Pubkey,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    if !(carrier.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner of carrier");
+    }
+
+    let mut carrier_device = carrier_device.account.clone();
+
+    assign!(carrier_device.borrow_mut().carrier, carrier.borrow().__account__.key());
+
+    assign!(carrier_device.borrow_mut().device, device);
+
+    assign!(carrier_device.borrow_mut().time_created, time);
+
+    let device_count = carrier.borrow().device_count + 1;
+
+    assign!(carrier.borrow_mut().device_count, device_count);
+}
+
+pub fn revoke_carrier_device_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut carrier: Mutable<LoadedCarrier<'info, '_>>,
+    mut carrier_device: Mutable<LoadedCarrierDevice<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !(carrier.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner of carrier");
+    }
+
+    if !(carrier_device.borrow().carrier == carrier.borrow().__account__.key()) {
+        panic!("Device is not registered to this carrier");
+    }
+
+    let device_count = carrier.borrow().device_count.checked_sub(1).unwrap();
+
+    assign!(carrier.borrow_mut().device_count, device_count);
+}
+
+pub fn handover_to_carrier_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut old_depot_owner: SeahorseSigner<'info, '_>,
+    mut carrier_owner: SeahorseSigner<'info, '_>,
+    mut old_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut old_depot_owner_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut old_depot_owner_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut carrier: Mutable<LoadedCarrier<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !depot_authorized(
+        &old_depot,
+        old_depot_owner.key(),
+        &old_depot_owner_role,
+        &old_depot_owner_hub_depot,
+        ROLE_SHIPPER!(),
+    ) {
+        panic!("Old depot owner is not the owner or a shipper of old depot");
+    }
+
+    if !(carrier.borrow().owner == carrier_owner.key()) {
+        panic!("Carrier owner is not the owner of carrier");
+    }
+
+    if !(items.borrow().depot == old_depot.borrow().__account__.key()) {
+        panic!("Old depot is not same as items depot");
+    }
+
+    check_items_not_frozen(&items);
+
+    check_dispatch_inspection(&old_depot, &items);
+
+    if !(items.borrow().expiry_time == 0 || time < items.borrow().expiry_time) {
+        panic!("Items are expired");
+    }
+
+    if items.borrow().non_compliant {
+        panic!("Items are not compliant with their environment range");
+    }
+
+    remove_items_from_depot(&old_depot, &items);
+
+    reset_sub_location(&items);
+
+    // Items held by a carrier are in no depot
+    assign!(items.borrow_mut().depot, Pubkey::default());
+
+    assign!(items.borrow_mut().carrier, carrier.borrow().__account__.key());
+
+    assign!(items.borrow_mut().carrier_pickup_depot, old_depot.borrow().__account__.key());
+
+    assign!(items.borrow_mut().carrier_pickup_time, time);
+
+    assign!(items.borrow_mut().cur_time, time);
+}
+
+pub fn deliver_from_carrier_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut transfer_rules: Mutable<LoadedTransferRules<'info, '_>>,
+    mut carrier_owner: SeahorseSigner<'info, '_>,
+    mut new_depot_owner: SeahorseSigner<'info, '_>,
+    mut carrier: Mutable<LoadedCarrier<'info, '_>>,
+    mut pickup_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_depot: Mutable<LoadedDepot<'info, '_>>,
+    mut new_depot_owner_role: Option<Mutable<LoadedDepotRole<'info, '_>>>,
+    mut new_depot_owner_hub_depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut organization: Option<Mutable<LoadedOrganization<'info, '_>>>,
+    mut new_depot_accreditation_authority: Option<Mutable<LoadedAccreditationAuthority<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut override_compliance: bool,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(carrier.borrow().owner == carrier_owner.key()) {
+        panic!("Carrier owner is not the owner of carrier");
+    }
+
+    if !(items.borrow().carrier == carrier.borrow().__account__.key()) {
+        panic!("Items are not held by this carrier");
+    }
+
+    if !(items.borrow().carrier_pickup_depot == pickup_depot.borrow().__account__.key()) {
+        panic!("Pickup depot is not where the carrier collected the items");
+    }
+
+    if !depot_authorized(
+        &new_depot,
+        new_depot_owner.key(),
+        &new_depot_owner_role,
+        &new_depot_owner_hub_depot,
+        ROLE_RECEIVER!(),
+    ) {
+        panic!("New depot owner is not the owner or a receiver of new depot");
+    }
+
+    check_items_not_frozen(&items);
+
+    // The pickup depot stands in as origin for the rules a direct transfer would meet
+    check_destination_verified(
+        time,
+        &pickup_depot,
+        &new_depot,
+        &organization,
+        &new_depot_accreditation_authority,
+        &items,
+    );
+
+    check_boundary_documents(&pickup_depot, &new_depot, &items);
+
+    check_transfer_rules(&transfer_rules, &pickup_depot, &new_depot, &items);
+
+    check_hazmat_licensed(&new_depot, items.borrow().classification_class.borrow().hazmat_class);
+
+    let new_depot_owner_signed = new_depot_owner.key() == new_depot.borrow().owner;
+
+    check_receiving_hours(time, &new_depot, &items, new_depot_owner_signed);
+
+    check_items_compliant(time, &items, override_compliance);
+
+    add_items_to_depot(&new_depot, &items);
+
+    assign!(items.borrow_mut().depot, new_depot.borrow().__account__.key());
+
+    assign!(items.borrow_mut().carrier, Pubkey::default());
+
+    assign!(items.borrow_mut().carrier_pickup_depot, Pubkey::default());
+
+    assign!(items.borrow_mut().cur_time, time);
+
+    assign!(items.borrow_mut().last_transfer_time, time);
+
+    record_arrival(time, &new_depot, &items);
+
+    let transfer_count = items.borrow().transfer_count + 1;
+
+    assign!(items.borrow_mut().transfer_count, transfer_count);
+
+    assign!(items.borrow_mut().pending_document_types, 0);
+}
+
+pub fn update_items_location_by_carrier_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut device: SeahorseSigner<'info, '_>,
+    mut carrier_device: Mutable<LoadedCarrierDevice<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut route_plan: Option<Mutable<LoadedRoutePlan<'info, '_>>>,
+    mut coordinates_class: Coordinates,
+    mut sensor_reading_class: Option<SensorReading>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(carrier_device.borrow().device == device.key()) {
+        panic!("Device is not the registered carrier device");
+    }
+
+    if !(items.borrow().carrier != Pubkey::default() && items.borrow().carrier == carrier_device.borrow().carrier) {
+        panic!("Items are not held by the carrier of this device");
+    }
+
+    check_items_not_frozen(&items);
+
+    check_max_speed(&config, &items, &coordinates_class, time);
+
+    check_route(&route_plan, &items, &coordinates_class, time);
+
+    if let Some(sensor_reading_class) = sensor_reading_class {
+        record_sensor_reading(&items, sensor_reading_class, time);
+    }
+
+    assign!(items.borrow_mut().coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+
+    assign!(items.borrow_mut().cur_time, time);
+
+    assign!(items.borrow_mut().last_updated_by, device.key());
+
+    assign!(carrier_device.borrow_mut().last_update_time, time);
+}
+
+pub fn file_route_plan_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut payer: SeahorseSigner<'info, '_>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut route_plan: Empty<Mutable<LoadedRoutePlan<'info, '_>>>,
+    mut waypoints: [Coordinates; 16],
+    mut waypoint_count: u8,
+    mut corridor_width_meters: f64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner");
+    }
+
+    if !(waypoint_count > 0 && (waypoint_count as u64) <= ROUTE_PLAN_MAX_WAYPOINTS!()) {
+        panic!("Route plan waypoint count is out of range");
+    }
+
+    if !(corridor_width_meters > 0.0) {
+        panic!("Corridor width must be positive");
+    }
+
+    let mut route_plan = route_plan.account.clone();
+
+    assign!(route_plan.borrow_mut().items, items.borrow().__account__.key());
+
+    assign!(route_plan.borrow_mut().waypoints, Mutable::<[Coordinates; 16]>::new(waypoints));
+
+    assign!(route_plan.borrow_mut().waypoint_count, waypoint_count);
+
+    assign!(route_plan.borrow_mut().corridor_width_meters, corridor_width_meters);
+
+    assign!(route_plan.borrow_mut().time_created, time);
+
+    assign!(items.borrow_mut().has_route_plan, true);
+}
+
+pub fn close_route_plan_handler<'info>(
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut route_plan: Mutable<LoadedRoutePlan<'info, '_>>,
+) -> () {
+    check_not_paused(&config);
+
+    if !(items.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner");
+    }
+
+    if !(route_plan.borrow().items == items.borrow().__account__.key()) {
+        panic!("Route plan is not for these items");
+    }
+
+    assign!(items.borrow_mut().has_route_plan, false);
+}
+
+pub fn set_delivery_commitment_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut owner: SeahorseSigner<'info, '_>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut destination_depot: Pubkey,
+    mut delivery_deadline: i64,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().owner == owner.key()) {
+        panic!("Owner is not the owner");
+    }
+
+    // A commitment is set once, so its outcome stays on record
+    if !(items.borrow().delivery_status == DeliveryStatus::Unset) {
+        panic!("Items already have a delivery commitment");
+    }
+
+    if !(delivery_deadline > time) {
+        panic!("Delivery deadline is in the past");
+    }
+
+    if !(destination_depot != items.borrow().depot) {
+        panic!("Items are already at destination depot");
+    }
+
+    assign!(items.borrow_mut().destination_depot, destination_depot);
+
+    assign!(items.borrow_mut().delivery_deadline, delivery_deadline);
+
+    assign!(items.borrow_mut().delivery_status, DeliveryStatus::Pending);
+}
+
+pub fn mark_items_overdue_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().delivery_status == DeliveryStatus::Pending) {
+        panic!("Items have no pending delivery");
+    }
+
+    if !(time > items.borrow().delivery_deadline) {
+        panic!("Delivery deadline has not passed");
+    }
+
+    assign!(items.borrow_mut().delivery_status, DeliveryStatus::Overdue);
+
+    assign!(items.borrow_mut().overdue_marked_at, time);
+}
+
+pub fn confirm_delivery_handler<'info>(
+    mut clock: Sysvar<'info, Clock>,
+    mut config: Mutable<LoadedConfig<'info, '_>>,
+    mut consignee: SeahorseSigner<'info, '_>,
+    mut depot: Option<Mutable<LoadedDepot<'info, '_>>>,
+    mut items: Mutable<LoadedItems<'info, '_>>,
+    mut coordinates_class: Coordinates,
+    mut note_hash: Option<[u8; 32]>,
+) -> () {
+    let mut time = clock.unix_timestamp;
+
+    check_not_paused(&config);
+
+    check_items_not_delivered(&items);
+
+    if !(items.borrow().consignee != Pubkey::default() && items.borrow().consignee == consignee.key()) {
+        panic!("Signer is not the consignee of items");
+    }
+
+    check_items_not_frozen(&items);
+
+    // Items handed over from a depot leave its counters
+    if items.borrow().depot != Pubkey::default() {
+        match &depot {
+            Some(depot) => {
+                if !(items.borrow().depot == depot.borrow().__account__.key()) {
+                    panic!("Depot is not same as items depot");
+                }
+
+                remove_items_from_depot(depot, &items);
+            }
+            None => panic!("Depot of items is required"),
+        }
+    }
+
+    reset_sub_location(&items);
+
+    assign!(items.borrow_mut().depot, Pubkey::default());
+
+    assign!(items.borrow_mut().carrier, Pubkey::default());
+
+    assign!(items.borrow_mut().delivered, true);
+
+    assign!(items.borrow_mut().delivered_at, time);
+
+    assign!(items.borrow_mut().delivery_coordinates_class, Mutable::<Coordinates>::new(coordinates_class));
+
+    if let Some(note_hash) = note_hash {
+        assign!(items.borrow_mut().has_delivery_note, true);
+
+        assign!(items.borrow_mut().delivery_note_hash, Mutable::<[u8; 32]>::new(note_hash));
+    }
+
+    assign!(items.borrow_mut().cur_time, time);
+
+    assign!(items.borrow_mut().last_updated_by, consignee.key());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ed25519_data(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset: u16 = 16;
+        let signature_offset: u16 = public_key_offset + 32;
+        let message_offset: u16 = signature_offset + 64;
+        let mut data = vec![1u8, 0u8];
+
+        for value in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(message);
+
+        return data;
+    }
+
+    fn jurisdiction(code: &str) -> [u8; 6] {
+        let mut bytes = [0u8; 6];
+
+        bytes[..code.len()].copy_from_slice(code.as_bytes());
+
+        return bytes;
+    }
+
+    fn hs_rule(hs_prefix: u32, hs_prefix_digits: u8) -> TransferRule {
+        return TransferRule {
+            hs_prefix,
+            hs_prefix_digits,
+            ..TransferRule::default()
+        };
+    }
+
+    fn coordinates(lat: f64, long: f64) -> Coordinates {
+        return Coordinates { lat, long };
+    }
+
+    fn account_info(key: Pubkey, owner: Pubkey, is_signer: bool, data: Vec<u8>) -> &'static AccountInfo<'static> {
+        return Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(key)),
+            is_signer,
+            true,
+            Box::leak(Box::new(0u64)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0,
+        )));
+    }
+
+    fn programs() -> &'static ProgramsMap<'static> {
+        return Box::leak(Box::new(ProgramsMap(std::collections::HashMap::new())));
+    }
+
+    fn account<T: AccountSerialize + AccountDeserialize + Owner + anchor_lang::Discriminator + Clone>() -> &'static mut Box<Account<'static, T>> {
+        let mut data = T::DISCRIMINATOR.to_vec();
+
+        data.resize(8 + std::mem::size_of::<T>(), 0);
+
+        let info = account_info(Pubkey::new_unique(), crate::ID, false, data);
+
+        return Box::leak(Box::new(Box::new(Account::try_from(info).unwrap())));
+    }
+
+    fn signer(key: Pubkey) -> SeahorseSigner<'static, 'static> {
+        let info = account_info(key, solana_program::system_program::ID, true, vec![]);
+
+        return SeahorseSigner {
+            account: Box::leak(Box::new(Signer::try_from(info).unwrap())),
+            programs: programs(),
+        };
+    }
+
+    fn clock(unix_timestamp: i64) -> Sysvar<'static, Clock> {
+        // Slot, epoch start timestamp, epoch and leader schedule epoch precede the timestamp
+        let mut data = vec![0u8; 32];
+
+        data.extend_from_slice(&unix_timestamp.to_le_bytes());
+
+        let info = account_info(
+            solana_program::sysvar::clock::ID,
+            solana_program::sysvar::ID,
+            false,
+            data,
+        );
+
+        return Sysvar::from_account_info(info).unwrap();
+    }
+
+    fn config() -> Mutable<LoadedConfig<'static, 'static>> {
+        return Config::load(account(), programs());
+    }
+
+    fn multisig_depot(owners: &[Pubkey], threshold: u8) -> (Mutable<LoadedMultisig<'static, 'static>>, Mutable<LoadedDepot<'static, 'static>>) {
+        let multisig = Multisig::load(account(), programs());
+        let depot = Depot::load(account(), programs());
+        let mut owner_array = [Pubkey::default(); 10];
+
+        owner_array[..owners.len()].copy_from_slice(owners);
+
+        create_multisig_handler(
+            clock(0),
+            signer(owners[0]),
+            config(),
+            signer(owners[0]),
+            Empty {
+                account: multisig.clone(),
+                bump: None,
+            },
+            owner_array,
+            owners.len() as u8,
+            threshold,
+            0,
+        );
+
+        assign!(depot.borrow_mut().owner, multisig.borrow().__account__.key());
+
+        return (multisig, depot);
+    }
+
+    fn propose(
+        multisig: &Mutable<LoadedMultisig<'static, 'static>>,
+        depot: &Mutable<LoadedDepot<'static, 'static>>,
+        proposer: Pubkey,
+        action: ProposalAction,
+    ) -> Mutable<LoadedMultisigProposal<'static, 'static>> {
+        let proposal = MultisigProposal::load(account(), programs());
+
+        propose_depot_action_handler(
+            clock(0),
+            signer(proposer),
+            config(),
+            signer(proposer),
+            multisig.clone(),
+            depot.clone(),
+            Empty {
+                account: proposal.clone(),
+                bump: None,
+            },
+            action,
+        );
+
+        return proposal;
+    }
+
+    fn depot_owned_by(owner: Pubkey) -> Mutable<LoadedDepot<'static, 'static>> {
+        let depot = Depot::load(account(), programs());
+
+        assign!(depot.borrow_mut().owner, owner);
+
+        return depot;
+    }
+
+    fn role_on(depot: &Mutable<LoadedDepot<'static, 'static>>, delegate: Pubkey, permissions: u8) -> Mutable<LoadedDepotRole<'static, 'static>> {
+        let depot_role = DepotRole::load(account(), programs());
+
+        grant_depot_role(depot, &depot_role, delegate, permissions, depot.borrow().owner, 0);
+
+        return depot_role;
+    }
+
+    fn linked_hub(hub_owner: Pubkey) -> (Mutable<LoadedDepot<'static, 'static>>, Mutable<LoadedDepot<'static, 'static>>) {
+        let hub_depot = depot_owned_by(hub_owner);
+        let child_depot = depot_owned_by(Pubkey::new_unique());
+
+        assign!(child_depot.borrow_mut().pending_parent_depot, hub_depot.borrow().__account__.key());
+
+        accept_child_depot_handler(config(), signer(hub_owner), hub_depot.clone(), None, None, child_depot.clone());
+
+        return (hub_depot, child_depot);
+    }
+
+    fn edit_depot_action() -> ProposalAction {
+        return ProposalAction::EditDepot {
+            name_array: [0; 32],
+            info_array: [0; 256],
+            coordinates_class: Coordinates::default(),
+        };
+    }
+
+    #[test]
+    fn ed25519_instruction_signed_matches_signer_and_message() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"challenge", u16::MAX);
+
+        assert!(ed25519_instruction_signed(&data, &signer, b"challenge"));
+        assert!(!ed25519_instruction_signed(&data, &signer, b"other"));
+        assert!(!ed25519_instruction_signed(&data, &Pubkey::new_unique(), b"challenge"));
+    }
+
+    #[test]
+    fn ed25519_instruction_signed_rejects_data_from_other_instructions() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"challenge", 0);
+
+        assert!(!ed25519_instruction_signed(&data, &signer, b"challenge"));
+    }
+
+    #[test]
+    fn ed25519_instruction_signed_rejects_truncated_data() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"challenge", u16::MAX);
+
+        assert!(!ed25519_instruction_signed(&[], &signer, b"challenge"));
+        assert!(!ed25519_instruction_signed(&data[..10], &signer, b"challenge"));
+        assert!(!ed25519_instruction_signed(&data[..data.len() - 1], &signer, b"challenge"));
+    }
+
+    #[test]
+    fn handoff_receipt_message_is_bound_to_domain_and_program() {
+        let handoff_receipt = HandoffReceipt {
+            items: Pubkey::new_unique(),
+            old_depot: Pubkey::new_unique(),
+            new_depot: Pubkey::new_unique(),
+            timestamp: 1_700_000_000,
+        };
+        let message = handoff_receipt_message(&crate::ID, &handoff_receipt);
+        let domain = HANDOFF_RECEIPT_DOMAIN!();
+
+        assert!(message.starts_with(domain));
+        assert_eq!(&message[domain.len()..domain.len() + 32], crate::ID.as_ref());
+        assert_eq!(&message[domain.len() + 32..], handoff_receipt.try_to_vec().unwrap().as_slice());
+        assert_ne!(message, handoff_receipt_message(&Pubkey::new_unique(), &handoff_receipt));
+    }
+
+    #[test]
+    fn slot_hash_in_finds_recent_slots_only() {
+        let mut data = 2u64.to_le_bytes().to_vec();
+
+        data.extend_from_slice(&11u64.to_le_bytes());
+        data.extend_from_slice(&[11u8; 32]);
+        data.extend_from_slice(&10u64.to_le_bytes());
+        data.extend_from_slice(&[10u8; 32]);
+
+        assert_eq!(slot_hash_in(&data, 10), Some([10u8; 32]));
+        assert_eq!(slot_hash_in(&data, 11), Some([11u8; 32]));
+        assert_eq!(slot_hash_in(&data, 9), None);
+        assert_eq!(slot_hash_in(&data[..50], 10), None);
+        assert_eq!(slot_hash_in(&[], 10), None);
+    }
+
+    #[test]
+    fn within_receiving_hours_uses_local_weekday_and_minute() {
+        let mut receiving_hours = ReceivingHours::default();
+
+        // 1970-01-05 was a Monday
+        let monday = 4 * 86400;
+
+        receiving_hours.open_minutes[1] = 9 * 60;
+        receiving_hours.close_minutes[1] = 17 * 60;
+
+        assert!(within_receiving_hours(&receiving_hours, monday + 9 * 3600));
+        assert!(within_receiving_hours(&receiving_hours, monday + 16 * 3600 + 59 * 60));
+        assert!(!within_receiving_hours(&receiving_hours, monday + 17 * 3600));
+        assert!(!within_receiving_hours(&receiving_hours, monday + 8 * 3600));
+        assert!(!within_receiving_hours(&receiving_hours, monday + 86400 + 10 * 3600));
+
+        receiving_hours.utc_offset_minutes = 120;
+
+        assert!(within_receiving_hours(&receiving_hours, monday + 7 * 3600 + 30 * 60));
+        assert!(!within_receiving_hours(&receiving_hours, monday + 15 * 3600));
+    }
+
+    #[test]
+    fn within_receiving_hours_crosses_midnight_with_offset() {
+        let mut receiving_hours = ReceivingHours::default();
+        let monday = 4 * 86400;
+
+        receiving_hours.utc_offset_minutes = -60;
+        receiving_hours.open_minutes[0] = 23 * 60;
+        receiving_hours.close_minutes[0] = 24 * 60;
+
+        // 00:30 UTC on Monday is still Sunday locally
+        assert!(within_receiving_hours(&receiving_hours, monday + 30 * 60));
+        assert!(!within_receiving_hours(&receiving_hours, monday + 90 * 60));
+    }
+
+    #[test]
+    fn jurisdiction_matches_countries_and_regions() {
+        assert!(jurisdiction_matches(&[0u8; 6], &jurisdiction("DE")));
+        assert!(jurisdiction_matches(&jurisdiction("US-CA"), &jurisdiction("US-CA")));
+        assert!(jurisdiction_matches(&jurisdiction("US"), &jurisdiction("US-CA")));
+        assert!(!jurisdiction_matches(&jurisdiction("US-CA"), &jurisdiction("US")));
+        assert!(!jurisdiction_matches(&jurisdiction("US-CA"), &jurisdiction("US-NY")));
+        assert!(!jurisdiction_matches(&jurisdiction("US"), &jurisdiction("DE")));
+    }
+
+    #[test]
+    fn hs_code_matches_prefixes() {
+        assert!(hs_code_matches(&hs_rule(85, 2), 851712));
+        assert!(!hs_code_matches(&hs_rule(85, 2), 841712));
+        assert!(hs_code_matches(&hs_rule(8517, 4), 851712));
+        assert!(hs_code_matches(&hs_rule(851712, 6), 851712));
+        assert!(!hs_code_matches(&hs_rule(851712, 6), 851713));
+        assert!(hs_code_matches(&hs_rule(0, 0), 10121));
+        assert!(!hs_code_matches(&hs_rule(851712, 7), 851712));
+    }
+
+    #[test]
+    fn transfer_rule_with_hs_prefix_and_hazmat_class_matches_both() {
+        let old_depot = depot_owned_by(Pubkey::new_unique());
+        let new_depot = depot_owned_by(Pubkey::new_unique());
+        let transfer_rules = TransferRules::load(account(), programs());
+        let items = Items::load(account(), programs());
+
+        transfer_rules.borrow().rules.borrow_mut()[0] = TransferRule {
+            hazmat_class: 3,
+            ..hs_rule(85, 2)
+        };
+
+        assign!(transfer_rules.borrow_mut().rule_count, 1);
+
+        let forbidden = |hs_code: u32, hazmat_class: u8| {
+            items.borrow().classification_class.borrow_mut().hs_code = hs_code;
+            items.borrow().classification_class.borrow_mut().hazmat_class = hazmat_class;
+
+            return std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                check_transfer_rules(&transfer_rules, &old_depot, &new_depot, &items)
+            }))
+            .is_err();
+        };
+
+        assert!(forbidden(851712, 3));
+        assert!(!forbidden(841712, 3));
+        assert!(!forbidden(851712, 0));
+        assert!(!forbidden(851712, 2));
+    }
+
+    #[test]
+    fn distance_to_segment_meters_measures_to_nearest_point() {
+        let from = coordinates(0.0, 0.0);
+        let to = coordinates(0.0, 1.0);
+        let meters_per_degree = EARTH_RADIUS_METERS!() * std::f64::consts::PI / 180.0;
+
+        assert!(distance_to_segment_meters(&coordinates(0.0, 0.5), &from, &to) < 1.0);
+
+        let beside = distance_to_segment_meters(&coordinates(0.01, 0.5), &from, &to);
+
+        assert!((beside - 0.01 * meters_per_degree).abs() < 1.0);
+
+        let beyond = distance_to_segment_meters(&coordinates(0.0, 1.5), &from, &to);
+
+        assert!((beyond - 0.5 * meters_per_degree).abs() < 1.0);
+
+        let point = coordinates(0.3, 0.4);
+        let degenerate = distance_to_segment_meters(&point, &from, &from);
+
+        assert!((degenerate - distance_meters(&point, &from)).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Proposal was made under a previous multisig owner set")]
+    fn set_owners_proposal_invalidates_pending_proposals() {
+        let owner = Pubkey::new_unique();
+        let (multisig, depot) = multisig_depot(&[owner], 1);
+        let pending = propose(&multisig, &depot, owner, edit_depot_action());
+        let mut owners = [Pubkey::default(); 10];
+
+        owners[0] = owner;
+        owners[1] = Pubkey::new_unique();
+
+        let set_owners = propose(
+            &multisig,
+            &depot,
+            owner,
+            ProposalAction::SetOwners {
+                owners,
+                owner_count: 2,
+                threshold: 2,
+            },
+        );
+
+        execute_depot_proposal_handler(clock(0), signer(owner), config(), multisig.clone(), set_owners, depot.clone());
+
+        assert_eq!(multisig.borrow().threshold, 2);
+        assert_eq!(multisig.borrow().owners_epoch, 1);
+
+        execute_depot_proposal_handler(clock(0), signer(owner), config(), multisig.clone(), pending, depot.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Proposal was made under a previous multisig owner set")]
+    fn removed_owner_approval_does_not_count_after_owner_change() {
+        let owner = Pubkey::new_unique();
+        let removed_owner = Pubkey::new_unique();
+        let (multisig, depot) = multisig_depot(&[owner, removed_owner], 2);
+        let pending = propose(&multisig, &depot, removed_owner, edit_depot_action());
+        let mut owners = [Pubkey::default(); 10];
+
+        owners[0] = owner;
+
+        let set_owners = propose(
+            &multisig,
+            &depot,
+            owner,
+            ProposalAction::SetOwners {
+                owners,
+                owner_count: 1,
+                threshold: 1,
+            },
+        );
+
+        approve_multisig_proposal_handler(signer(removed_owner), config(), signer(removed_owner), multisig.clone(), set_owners.clone());
+
+        execute_depot_proposal_handler(clock(0), signer(owner), config(), multisig.clone(), set_owners, depot.clone());
+
+        // The removed owner's approval alone would meet the new threshold
+        execute_depot_proposal_handler(clock(0), signer(owner), config(), multisig.clone(), pending, depot.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Proposal was made under a previous depot owner")]
+    fn depot_owner_change_invalidates_pending_proposals() {
+        let owner = Pubkey::new_unique();
+        let (multisig, depot) = multisig_depot(&[owner], 1);
+        let pending = propose(&multisig, &depot, owner, edit_depot_action());
+        let multisig_key = multisig.borrow().__account__.key();
+
+        // Handing the depot away and back must not revive proposals from before
+        set_depot_owner(&depot, Pubkey::new_unique());
+        set_depot_owner(&depot, multisig_key);
+
+        execute_depot_proposal_handler(clock(0), signer(owner), config(), multisig.clone(), pending, depot.clone());
+    }
+
+    #[test]
+    fn grant_role_proposal_grants_role_for_current_depot_owner() {
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let (multisig, depot) = multisig_depot(&[owner], 1);
+
+        set_depot_owner(&depot, multisig.borrow().__account__.key());
+
+        let proposal = propose(
+            &multisig,
+            &depot,
+            owner,
+            ProposalAction::GrantRole {
+                delegate,
+                permissions: ROLE_SHIPPER!(),
+            },
+        );
+        let depot_role = DepotRole::load(account(), programs());
+
+        grant_depot_role_by_multisig_handler(
+            clock(100),
+            signer(owner),
+            config(),
+            multisig.clone(),
+            proposal.clone(),
+            depot.clone(),
+            Empty {
+                account: depot_role.clone(),
+                bump: None,
+            },
+            delegate,
+        );
+
+        assert!(proposal.borrow().executed);
+        assert_eq!(depot_role.borrow().delegate, delegate);
+        assert_eq!(depot_role.borrow().granted_by, multisig.borrow().__account__.key());
+        assert_eq!(depot_role.borrow().owner_epoch, depot.borrow().owner_epoch);
+        assert!(depot_authorized(&depot, delegate, &Some(depot_role), &None, ROLE_SHIPPER!()));
+    }
+
+    #[test]
+    fn hub_admin_role_requires_hub_and_current_epochs() {
+        let hub_owner = Pubkey::new_unique();
+        let hub_admin = Pubkey::new_unique();
+        let (hub_depot, child_depot) = linked_hub(hub_owner);
+        let hub_role = Some(role_on(&hub_depot, hub_admin, ROLE_ADMIN!()));
+
+        assert!(depot_authorized(&child_depot, hub_owner, &None, &Some(hub_depot.clone()), ROLE_SHIPPER!()));
+        assert!(depot_authorized(&child_depot, hub_admin, &hub_role, &Some(hub_depot.clone()), ROLE_SHIPPER!()));
+        assert!(!depot_authorized(&child_depot, hub_admin, &hub_role, &None, ROLE_SHIPPER!()));
+
+        let other_depot = Some(depot_owned_by(hub_owner));
+
+        assert!(!depot_authorized(&child_depot, hub_admin, &hub_role, &other_depot, ROLE_SHIPPER!()));
+        assert!(!depot_authorized(&child_depot, hub_owner, &None, &other_depot, ROLE_SHIPPER!()));
+    }
+
+    #[test]
+    fn hub_admin_role_lapses_when_hub_changes_owner() {
+        let hub_admin = Pubkey::new_unique();
+        let (hub_depot, child_depot) = linked_hub(Pubkey::new_unique());
+        let hub_role = Some(role_on(&hub_depot, hub_admin, ROLE_ADMIN!()));
+
+        set_depot_owner(&hub_depot, Pubkey::new_unique());
+
+        assert!(!depot_authorized(&hub_depot, hub_admin, &hub_role, &None, ROLE_SHIPPER!()));
+        assert!(!depot_authorized(&child_depot, hub_admin, &hub_role, &Some(hub_depot.clone()), ROLE_SHIPPER!()));
+    }
+
+    #[test]
+    fn hub_authority_lapses_when_child_changes_owner() {
+        let hub_owner = Pubkey::new_unique();
+        let hub_admin = Pubkey::new_unique();
+        let (hub_depot, child_depot) = linked_hub(hub_owner);
+        let hub_role = Some(role_on(&hub_depot, hub_admin, ROLE_ADMIN!()));
+
+        set_depot_owner(&child_depot, Pubkey::new_unique());
+
+        assert!(!depot_authorized(&child_depot, hub_owner, &None, &Some(hub_depot.clone()), ROLE_SHIPPER!()));
+        assert!(!depot_authorized(&child_depot, hub_admin, &hub_role, &Some(hub_depot.clone()), ROLE_SHIPPER!()));
+    }
+
+    #[test]
+    fn hub_totals_follow_roll_ups_and_detach() {
+        let hub_owner = Pubkey::new_unique();
+        let (hub_depot, child_depot) = linked_hub(hub_owner);
+
+        assign!(child_depot.borrow_mut().item_count, 2);
+
+        assign!(child_depot.borrow_mut().total_weight_grams, 500);
+
+        sync_depot_rollup_handler(config(), child_depot.clone(), hub_depot.clone());
+
+        assert_eq!(hub_depot.borrow().child_count, 1);
+        assert_eq!(hub_depot.borrow().children_item_count, 2);
+        assert_eq!(hub_depot.borrow().children_weight_grams, 500);
+
+        assign!(child_depot.borrow_mut().item_count, 1);
+
+        assign!(child_depot.borrow_mut().total_weight_grams, 200);
+
+        sync_depot_rollup_handler(config(), child_depot.clone(), hub_depot.clone());
+
+        assert_eq!(hub_depot.borrow().children_item_count, 1);
+        assert_eq!(hub_depot.borrow().children_weight_grams, 200);
+
+        detach_child_depot_handler(config(), signer(hub_owner), hub_depot.clone(), child_depot.clone(), None, None, None);
+
+        assert_eq!(hub_depot.borrow().child_count, 0);
+        assert_eq!(hub_depot.borrow().children_item_count, 0);
+        assert_eq!(hub_depot.borrow().children_weight_grams, 0);
+        assert_eq!(child_depot.borrow().parent_depot, Pubkey::default());
+        assert_eq!(child_depot.borrow().parent_link_epoch, 0);
+        assert_eq!(child_depot.borrow().reported_item_count, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Authority is not the owner or an admin of either depot")]
+    fn detach_rejects_stale_hub_admin_role() {
+        let hub_admin = Pubkey::new_unique();
+        let (hub_depot, child_depot) = linked_hub(Pubkey::new_unique());
+        let hub_role = role_on(&hub_depot, hub_admin, ROLE_ADMIN!());
+
+        set_depot_owner(&hub_depot, Pubkey::new_unique());
+
+        detach_child_depot_handler(
+            config(),
+            signer(hub_admin),
+            hub_depot.clone(),
+            child_depot.clone(),
+            Some(hub_role.clone()),
+            Some(hub_role),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Program is paused")]
+    fn paused_program_rejects_depot_owner_change() {
+        let owner = Pubkey::new_unique();
+        let config = config();
+        let depot = depot_owned_by(owner);
+
+        assign!(config.borrow_mut().paused, true);
+
+        change_depot_owner_handler(signer(owner), config, signer(owner), depot, Pubkey::new_unique());
+    }
+
+    #[test]
+    fn depot_verification_lapses_with_its_authority() {
+        let authority = Pubkey::new_unique();
+        let depot = depot_owned_by(Pubkey::new_unique());
+        let accreditation_authority = AccreditationAuthority::load(account(), programs());
+
+        assign!(accreditation_authority.borrow_mut().authority, authority);
+
+        assign!(accreditation_authority.borrow_mut().active, true);
+
+        issue_depot_verification_handler(
+            clock(100),
+            config(),
+            signer(authority),
+            accreditation_authority.clone(),
+            depot.clone(),
+            0,
+            [0; 32],
+        );
+
+        let accreditation_authority = Some(accreditation_authority);
+
+        assert!(depot_verified(&depot, &accreditation_authority, 200));
+        assert!(!depot_verified(&depot, &None, 200));
+        assert!(!depot_verified(&depot, &Some(AccreditationAuthority::load(account(), programs())), 200));
+
+        assign!(accreditation_authority.as_ref().unwrap().borrow_mut().active, false);
+
+        assert!(!depot_verified(&depot, &accreditation_authority, 200));
+    }
+
+    #[test]
+    fn boundary_documents_apply_across_countries_and_unset_jurisdictions() {
+        let old_depot = depot_owned_by(Pubkey::new_unique());
+        let new_depot = depot_owned_by(Pubkey::new_unique());
+        let items = Items::load(account(), programs());
+
+        assign!(new_depot.borrow_mut().boundary_required_documents, 1);
+
+        let crosses_boundary = |old_code: &str, new_code: &str| {
+            assign!(old_depot.borrow_mut().jurisdiction_code, Mutable::<[u8; 6]>::new(jurisdiction(old_code)));
+
+            assign!(new_depot.borrow_mut().jurisdiction_code, Mutable::<[u8; 6]>::new(jurisdiction(new_code)));
+
+            return std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                check_boundary_documents(&old_depot, &new_depot, &items)
+            }))
+            .is_err();
+        };
+
+        assert!(!crosses_boundary("US-CA", "US-NY"));
+        assert!(crosses_boundary("US", "DE"));
+        assert!(crosses_boundary("", ""));
+        assert!(crosses_boundary("", "DE"));
+        assert!(crosses_boundary("DE", ""));
+
+        assign!(items.borrow_mut().pending_document_types, 1);
+
+        assert!(!crosses_boundary("", "DE"));
+    }
+
+    fn partner_depots(max_transfers_per_day: u32) -> (Mutable<LoadedDepot<'static, 'static>>, Mutable<LoadedDepot<'static, 'static>>, Option<Mutable<LoadedPartnerAgreement<'static, 'static>>>) {
+        let old_depot = depot_owned_by(Pubkey::new_unique());
+        let new_depot = depot_owned_by(Pubkey::new_unique());
+        let old_depot_key = old_depot.borrow().__account__.key();
+        let new_depot_key = new_depot.borrow().__account__.key();
+        let partner_agreement = PartnerAgreement::load(account(), programs());
+
+        assign!(partner_agreement.borrow_mut().depot_a, old_depot_key.min(new_depot_key));
+
+        assign!(partner_agreement.borrow_mut().depot_b, old_depot_key.max(new_depot_key));
+
+        assign!(partner_agreement.borrow_mut().max_transfers_per_day, max_transfers_per_day);
+
+        assign!(partner_agreement.borrow_mut().expiry_time, i64::MAX);
+
+        return (old_depot, new_depot, Some(partner_agreement));
+    }
+
+    #[test]
+    fn partner_agreement_limits_transfers_per_day() {
+        let (old_depot, new_depot, partner_agreement) = partner_depots(2);
+        let items = Items::load(account(), programs());
+        let day = 86400;
+
+        assert!(partner_transfer_allowed(day, &partner_agreement, &old_depot, &new_depot, &items));
+        assert!(partner_transfer_allowed(day + 60, &partner_agreement, &new_depot, &old_depot, &items));
+        assert!(!partner_transfer_allowed(day + 120, &partner_agreement, &old_depot, &new_depot, &items));
+        assert_eq!(partner_agreement.as_ref().unwrap().borrow().transfers_today, 2);
+
+        // The count starts over on the next UTC day
+        assert!(partner_transfer_allowed(2 * day, &partner_agreement, &old_depot, &new_depot, &items));
+        assert_eq!(partner_agreement.as_ref().unwrap().borrow().transfers_today, 1);
+    }
+
+    #[test]
+    fn partner_agreement_sentinels_allow_everything() {
+        let (old_depot, new_depot, partner_agreement) = partner_depots(0);
+        let items = Items::load(account(), programs());
+
+        items.borrow().classification_class.borrow_mut().hs_code = 851712;
+
+        for _ in 0..10 {
+            assert!(partner_transfer_allowed(100, &partner_agreement, &old_depot, &new_depot, &items));
+        }
+
+        let mut allowed_hs_chapters = [0u8; 13];
+
+        allowed_hs_chapters[84 / 8] |= 1 << (84 % 8);
+
+        assign!(partner_agreement.as_ref().unwrap().borrow_mut().allowed_hs_chapters, Mutable::<[u8; 13]>::new(allowed_hs_chapters));
+
+        assert!(!partner_transfer_allowed(100, &partner_agreement, &old_depot, &new_depot, &items));
+
+        items.borrow().classification_class.borrow_mut().hs_code = 841712;
+
+        assert!(partner_transfer_allowed(100, &partner_agreement, &old_depot, &new_depot, &items));
+    }
+
+    #[test]
+    fn partner_agreement_lapses_when_either_depot_changes_owner() {
+        let (old_depot, new_depot, partner_agreement) = partner_depots(0);
+        let items = Items::load(account(), programs());
+
+        assert!(partner_transfer_allowed(100, &partner_agreement, &old_depot, &new_depot, &items));
+
+        set_depot_owner(&new_depot, Pubkey::new_unique());
+
+        assert!(!partner_transfer_allowed(100, &partner_agreement, &old_depot, &new_depot, &items));
+        assert!(!partner_transfer_allowed(100, &None, &old_depot, &new_depot, &items));
+    }
+
+    fn items_in(depot: &Mutable<LoadedDepot<'static, 'static>>) -> Mutable<LoadedItems<'static, 'static>> {
+        let items = Items::load(account(), programs());
+
+        assign!(items.borrow_mut().depot, depot.borrow().__account__.key());
+
+        add_items_to_depot(depot, &items);
+
+        return items;
+    }
+
+    #[test]
+    fn carrier_holds_items_between_handover_and_delivery() {
+        let old_owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let carrier_owner = Pubkey::new_unique();
+        let old_depot = depot_owned_by(old_owner);
+        let new_depot = depot_owned_by(new_owner);
+        let carrier = Carrier::load(account(), programs());
+        let items = items_in(&old_depot);
+
+        assign!(carrier.borrow_mut().owner, carrier_owner);
+
+        handover_to_carrier_handler(
+            clock(1000),
+            signer(old_owner),
+            config(),
+            signer(old_owner),
+            signer(carrier_owner),
+            old_depot.clone(),
+            None,
+            None,
+            carrier.clone(),
+            items.clone(),
+        );
+
+        assert_eq!(items.borrow().depot, Pubkey::default());
+        assert_eq!(items.borrow().carrier, carrier.borrow().__account__.key());
+        assert_eq!(items.borrow().carrier_pickup_depot, old_depot.borrow().__account__.key());
+        assert_eq!(old_depot.borrow().item_count, 0);
+
+        deliver_from_carrier_handler(
+            clock(2000),
+            signer(new_owner),
+            config(),
+            TransferRules::load(account(), programs()),
+            signer(carrier_owner),
+            signer(new_owner),
+            carrier.clone(),
+            old_depot.clone(),
+            new_depot.clone(),
+            None,
+            None,
+            None,
+            None,
+            items.clone(),
+            false,
+        );
+
+        assert_eq!(items.borrow().depot, new_depot.borrow().__account__.key());
+        assert_eq!(items.borrow().carrier, Pubkey::default());
+        assert_eq!(items.borrow().carrier_pickup_depot, Pubkey::default());
+        assert_eq!(items.borrow().last_transfer_time, 2000);
+        assert_eq!(items.borrow().transfer_count, 1);
+        assert_eq!(new_depot.borrow().item_count, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Items are not held by this carrier")]
+    fn other_carrier_cannot_deliver_items() {
+        let old_owner = Pubkey::new_unique();
+        let new_owner = Pubkey::new_unique();
+        let carrier_owner = Pubkey::new_unique();
+        let old_depot = depot_owned_by(old_owner);
+        let carrier = Carrier::load(account(), programs());
+        let other_carrier = Carrier::load(account(), programs());
+        let items = items_in(&old_depot);
+
+        assign!(carrier.borrow_mut().owner, carrier_owner);
+
+        assign!(other_carrier.borrow_mut().owner, carrier_owner);
+
+        handover_to_carrier_handler(
+            clock(1000),
+            signer(old_owner),
+            config(),
+            signer(old_owner),
+            signer(carrier_owner),
+            old_depot.clone(),
+            None,
+            None,
+            carrier,
+            items.clone(),
+        );
+
+        deliver_from_carrier_handler(
+            clock(2000),
+            signer(new_owner),
+            config(),
+            TransferRules::load(account(), programs()),
+            signer(carrier_owner),
+            signer(new_owner),
+            other_carrier,
+            old_depot,
+            depot_owned_by(new_owner),
+            None,
+            None,
+            None,
+            None,
+            items,
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Old depot owner is not the owner or a shipper of old depot")]
+    fn handover_requires_a_shipper_of_the_old_depot() {
+        let carrier_owner = Pubkey::new_unique();
+        let old_depot = depot_owned_by(Pubkey::new_unique());
+        let carrier = Carrier::load(account(), programs());
+        let receiver = Pubkey::new_unique();
+        let receiver_role = Some(role_on(&old_depot, receiver, ROLE_RECEIVER!()));
+        let items = items_in(&old_depot);
+
+        assign!(carrier.borrow_mut().owner, carrier_owner);
+
+        handover_to_carrier_handler(
+            clock(1000),
+            signer(receiver),
+            config(),
+            signer(receiver),
+            signer(carrier_owner),
+            old_depot,
+            receiver_role,
+            None,
+            carrier,
+            items,
+        );
+    }
+
+    fn committed_items(destination_depot: &Mutable<LoadedDepot<'static, 'static>>, delivery_deadline: i64) -> Mutable<LoadedItems<'static, 'static>> {
+        let owner = Pubkey::new_unique();
+        let items = items_in(&depot_owned_by(owner));
+
+        assign!(items.borrow_mut().owner, owner);
+
+        set_delivery_commitment_handler(
+            clock(0),
+            config(),
+            signer(owner),
+            items.clone(),
+            destination_depot.borrow().__account__.key(),
+            delivery_deadline,
+        );
+
+        assert!(items.borrow().delivery_status == DeliveryStatus::Pending);
+
+        return items;
+    }
+
+    #[test]
+    fn delivery_on_time_only_counts_at_destination() {
+        let destination_depot = depot_owned_by(Pubkey::new_unique());
+        let items = committed_items(&destination_depot, 1000);
+
+        record_arrival(500, &depot_owned_by(Pubkey::new_unique()), &items);
+
+        assert!(items.borrow().delivery_status == DeliveryStatus::Pending);
+
+        record_arrival(900, &destination_depot, &items);
+
+        assert!(items.borrow().delivery_status == DeliveryStatus::OnTime);
+        assert_eq!(items.borrow().arrival_time, 900);
+        assert_eq!(items.borrow().lateness_seconds, 0);
+
+        // The outcome is final once recorded
+        record_arrival(5000, &destination_depot, &items);
+
+        assert!(items.borrow().delivery_status == DeliveryStatus::OnTime);
+        assert_eq!(items.borrow().arrival_time, 900);
+    }
+
+    #[test]
+    fn overdue_delivery_arrives_late() {
+        let destination_depot = depot_owned_by(Pubkey::new_unique());
+        let items = committed_items(&destination_depot, 1000);
+
+        mark_items_overdue_handler(clock(1500), config(), items.clone());
+
+        assert!(items.borrow().delivery_status == DeliveryStatus::Overdue);
+        assert_eq!(items.borrow().overdue_marked_at, 1500);
+
+        record_arrival(2000, &destination_depot, &items);
+
+        assert!(items.borrow().delivery_status == DeliveryStatus::Late);
+        assert_eq!(items.borrow().lateness_seconds, 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Delivery deadline has not passed")]
+    fn items_are_not_overdue_before_deadline() {
+        let items = committed_items(&depot_owned_by(Pubkey::new_unique()), 1000);
+
+        mark_items_overdue_handler(clock(1000), config(), items);
+    }
+
+    #[test]
+    #[should_panic(expected = "Items already have a delivery commitment")]
+    fn delivery_commitment_is_set_once() {
+        let destination_depot = depot_owned_by(Pubkey::new_unique());
+        let items = committed_items(&destination_depot, 1000);
+        let owner = items.borrow().owner;
+
+        set_delivery_commitment_handler(
+            clock(0),
+            config(),
+            signer(owner),
+            items.clone(),
+            destination_depot.borrow().__account__.key(),
+            2000,
+        );
+    }
 }
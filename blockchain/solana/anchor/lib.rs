@@ -1,6 +1,15 @@
 #![allow(unused_imports)]
 #![allow(unused_variables)]
 #![allow(unused_mut)]
+// Idioms of the Seahorse code generator the program was written in
+#![allow(clippy::unused_unit)]
+#![allow(clippy::needless_return)]
+#![allow(clippy::nonminimal_bool)]
+#![allow(clippy::neg_cmp_op_on_partial_ord)]
+#![allow(clippy::clone_on_copy)]
+#![allow(clippy::useless_asref)]
+#![allow(clippy::derivable_impls)]
+#![allow(clippy::too_many_arguments)]
 
 pub mod dot;
 
@@ -105,7 +114,7 @@ pub mod seahorse_util {
         type Target = A;
 
         fn deref(&self) -> &Self::Target {
-            &self.account
+            self.account
         }
     }
 
@@ -175,6 +184,8 @@ mod electra_chain {
         pub clock: Sysvar<'info, Clock>,
         #[account(mut)]
         pub payer: Signer<'info>,
+        # [account (mut , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
         #[account(mut)]
         pub owner: Signer<'info>,
         # [account (init , space = std :: mem :: size_of :: < dot :: program :: Depot > () + 8 , payer = payer , seeds = [owner . key () . as_ref () , "depot" . as_bytes () . as_ref () , seed_random . to_le_bytes () . as_ref ()] , bump)]
@@ -204,6 +215,8 @@ mod electra_chain {
             programs: &programs_map,
         };
 
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
         let owner = SeahorseSigner {
             account: &ctx.accounts.owner,
             programs: &programs_map,
@@ -217,6 +230,7 @@ mod electra_chain {
         init_depot_handler(
             clock.clone(),
             payer.clone(),
+            config.clone(),
             owner.clone(),
             depot.clone(),
             name_array,
@@ -227,22 +241,30 @@ mod electra_chain {
 
         dot::program::Depot::store(depot.account);
 
+        dot::program::Config::store(config);
+
         return Ok(());
     }
 
     #[derive(Accounts)]
-    # [instruction (name_array: [u16; 32] , info_array: [u16; 256] , coordinates_class: Coordinates , seed_random : u128)]
+    # [instruction (name_array: [u16; 32] , info_array: [u16; 256] , coordinates_class: Coordinates , seed_random : u128 , expiry_time : i64 , environment_range_class : Option < EnvironmentRange > , tag_pubkey : Pubkey , classification_class : GoodsClassification , weight_grams : u64 , volume_cm3 : u64 , consignee : Pubkey)]
     pub struct InitItems<'info> {
         #[account()]
         pub clock: Sysvar<'info, Clock>,
         #[account(mut)]
         pub payer: Signer<'info>,
+        # [account (mut , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
         #[account(mut)]
         pub owner: Signer<'info>,
         #[account(mut)]
         pub depot_signer: Signer<'info>,
         #[account(mut)]
         pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub depot_signer_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub depot_signer_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
         # [account (init , space = std :: mem :: size_of :: < dot :: program :: Items > () + 8 , payer = payer , seeds = [owner . key () . as_ref () , depot_signer . key () . as_ref () , "items" . as_bytes () . as_ref () , seed_random . to_le_bytes () . as_ref ()] , bump)]
         pub items: Box<Account<'info, dot::program::Items>>,
         pub rent: Sysvar<'info, Rent>,
@@ -255,6 +277,13 @@ mod electra_chain {
         info_array: [u16; 256],
         coordinates_class: Coordinates,
         seed_random: u128,
+        expiry_time: i64,
+        environment_range_class: Option<EnvironmentRange>,
+        tag_pubkey: Pubkey,
+        classification_class: GoodsClassification,
+        weight_grams: u64,
+        volume_cm3: u64,
+        consignee: Pubkey,
     ) -> Result<()> {
         let mut programs = HashMap::new();
 
@@ -270,6 +299,8 @@ mod electra_chain {
             programs: &programs_map,
         };
 
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
         let owner = SeahorseSigner {
             account: &ctx.accounts.owner,
             programs: &programs_map,
@@ -281,6 +312,18 @@ mod electra_chain {
         };
 
         let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let depot_signer_role = ctx
+            .accounts
+            .depot_signer_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let depot_signer_hub_depot = ctx
+            .accounts
+            .depot_signer_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
         let items = Empty {
             account: dot::program::Items::load(&mut ctx.accounts.items, &programs_map),
             bump: Some(ctx.bumps.items),
@@ -289,42 +332,73 @@ mod electra_chain {
         init_items_handler(
             clock.clone(),
             payer.clone(),
+            config.clone(),
             owner.clone(),
             depot_signer.clone(),
             depot.clone(),
+            depot_signer_role.clone(),
+            depot_signer_hub_depot.clone(),
             items.clone(),
             name_array,
             info_array,
             coordinates_class,
             seed_random,
+            expiry_time,
+            environment_range_class,
+            tag_pubkey,
+            classification_class,
+            weight_grams,
+            volume_cm3,
+            consignee,
         );
 
         dot::program::Depot::store(depot);
 
         dot::program::Items::store(items.account);
 
+        dot::program::Config::store(config);
+
         return Ok(());
     }
 
     #[derive(Accounts)]
+    # [instruction (override_compliance : bool)]
     pub struct TransferItems<'info> {
         #[account()]
         pub clock: Sysvar<'info, Clock>,
         #[account(mut)]
         pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
         #[account(mut)]
         pub old_depot_owner: Signer<'info>,
         #[account(mut)]
-        pub new_depot_owner: Signer<'info>,
+        pub new_depot_owner: Option<Signer<'info>>,
         #[account(mut)]
         pub old_depot: Box<Account<'info, dot::program::Depot>>,
         #[account(mut)]
         pub new_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub old_depot_owner_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub old_depot_owner_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account()]
+        pub new_depot_owner_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub new_depot_owner_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account()]
+        pub organization: Option<Box<Account<'info, dot::program::Organization>>>,
+        #[account(mut)]
+        pub partner_agreement: Option<Box<Account<'info, dot::program::PartnerAgreement>>>,
+        #[account()]
+        pub new_depot_accreditation_authority: Option<Box<Account<'info, dot::program::AccreditationAuthority>>>,
         #[account(mut)]
         pub items: Box<Account<'info, dot::program::Items>>,
     }
 
-    pub fn transfer_items(ctx: Context<TransferItems>) -> Result<()> {
+    pub fn transfer_items(ctx: Context<TransferItems>, override_compliance: bool) -> Result<()> {
         let mut programs = HashMap::new();
         let programs_map = ProgramsMap(programs);
         let clock = &ctx.accounts.clock.clone();
@@ -333,28 +407,88 @@ mod electra_chain {
             programs: &programs_map,
         };
 
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules =
+            dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map);
+
         let old_depot_owner = SeahorseSigner {
             account: &ctx.accounts.old_depot_owner,
             programs: &programs_map,
         };
 
-        let new_depot_owner = SeahorseSigner {
-            account: &ctx.accounts.new_depot_owner,
-            programs: &programs_map,
-        };
+        let new_depot_owner = ctx
+            .accounts
+            .new_depot_owner
+            .as_ref()
+            .map(|account| SeahorseSigner {
+                account,
+                programs: &programs_map,
+            });
 
         let old_depot = dot::program::Depot::load(&mut ctx.accounts.old_depot, &programs_map);
         let new_depot = dot::program::Depot::load(&mut ctx.accounts.new_depot, &programs_map);
+        let old_depot_owner_role = ctx
+            .accounts
+            .old_depot_owner_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let old_depot_owner_hub_depot = ctx
+            .accounts
+            .old_depot_owner_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let new_depot_owner_role = ctx
+            .accounts
+            .new_depot_owner_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let new_depot_owner_hub_depot = ctx
+            .accounts
+            .new_depot_owner_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let organization = ctx
+            .accounts
+            .organization
+            .as_mut()
+            .map(|account| dot::program::Organization::load(account, &programs_map));
+
+        let partner_agreement = ctx
+            .accounts
+            .partner_agreement
+            .as_mut()
+            .map(|account| dot::program::PartnerAgreement::load(account, &programs_map));
+
+        let new_depot_accreditation_authority = ctx
+            .accounts
+            .new_depot_accreditation_authority
+            .as_mut()
+            .map(|account| dot::program::AccreditationAuthority::load(account, &programs_map));
+
         let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
 
         transfer_items_handler(
             clock.clone(),
             payer.clone(),
+            config.clone(),
+            transfer_rules.clone(),
             old_depot_owner.clone(),
             new_depot_owner.clone(),
             old_depot.clone(),
             new_depot.clone(),
+            old_depot_owner_role.clone(),
+            old_depot_owner_hub_depot.clone(),
+            new_depot_owner_role.clone(),
+            new_depot_owner_hub_depot.clone(),
+            organization.clone(),
+            partner_agreement.clone(),
+            new_depot_accreditation_authority.clone(),
             items.clone(),
+            override_compliance,
         );
 
         dot::program::Depot::store(old_depot);
@@ -363,25 +497,34 @@ mod electra_chain {
 
         dot::program::Items::store(items);
 
+        if let Some(partner_agreement) = partner_agreement {
+            dot::program::PartnerAgreement::store(partner_agreement);
+        }
+
         return Ok(());
     }
 
     #[derive(Accounts)]
-    # [instruction (coordinates_class: Coordinates)]
+    # [instruction (coordinates_class: Coordinates , sensor_reading_class : Option < SensorReading >)]
     pub struct UpdateItems<'info> {
         #[account()]
         pub clock: Sysvar<'info, Clock>,
         #[account(mut)]
         pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
         #[account(mut)]
         pub owner: Signer<'info>,
         #[account(mut)]
         pub items: Box<Account<'info, dot::program::Items>>,
+        #[account(mut)]
+        pub route_plan: Option<Box<Account<'info, dot::program::RoutePlan>>>,
     }
 
     pub fn update_items(
         ctx: Context<UpdateItems>,
         coordinates_class: Coordinates,
+        sensor_reading_class: Option<SensorReading>,
     ) -> Result<()> {
         let mut programs = HashMap::new();
         let programs_map = ProgramsMap(programs);
@@ -391,21 +534,4579 @@ mod electra_chain {
             programs: &programs_map,
         };
 
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
         let owner = SeahorseSigner {
             account: &ctx.accounts.owner,
             programs: &programs_map,
         };
 
         let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let route_plan = ctx
+            .accounts
+            .route_plan
+            .as_mut()
+            .map(|account| dot::program::RoutePlan::load(account, &programs_map));
 
         update_items_handler(
             clock.clone(),
             payer.clone(),
+            config.clone(),
             owner.clone(),
             items.clone(),
+            route_plan.clone(),
+            coordinates_class,
+            sensor_reading_class,
+        );
+
+        dot::program::Items::store(items);
+
+        if let Some(route_plan) = route_plan {
+            dot::program::RoutePlan::store(route_plan);
+        }
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (device : Pubkey , expiry_time : i64)]
+    pub struct AuthorizeDevice<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: DeviceAuthorization > () + 8 , payer = payer , seeds = [items . key () . as_ref () , device . as_ref () , "device" . as_bytes () . as_ref ()] , bump)]
+        pub device_authorization: Box<Account<'info, dot::program::DeviceAuthorization>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn authorize_device(
+        ctx: Context<AuthorizeDevice>,
+        device: Pubkey,
+        expiry_time: i64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let device_authorization = Empty {
+            account: dot::program::DeviceAuthorization::load(
+                &mut ctx.accounts.device_authorization,
+                &programs_map,
+            ),
+            bump: Some(ctx.bumps.device_authorization),
+        };
+
+        authorize_device_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            items.clone(),
+            device_authorization.clone(),
+            device,
+            expiry_time,
+        );
+
+        dot::program::Depot::store(depot);
+
+        dot::program::Items::store(items);
+
+        dot::program::DeviceAuthorization::store(device_authorization.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct RevokeDevice<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        #[account(mut, close = payer)]
+        pub device_authorization: Box<Account<'info, dot::program::DeviceAuthorization>>,
+    }
+
+    pub fn revoke_device(ctx: Context<RevokeDevice>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let device_authorization = dot::program::DeviceAuthorization::load(
+            &mut ctx.accounts.device_authorization,
+            &programs_map,
+        );
+
+        revoke_device_handler(
+            payer.clone(),
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            items.clone(),
+            device_authorization.clone(),
+        );
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (coordinates_class: Coordinates)]
+    pub struct UpdateItemsLocation<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub device: Signer<'info>,
+        #[account(mut)]
+        pub device_authorization: Box<Account<'info, dot::program::DeviceAuthorization>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        #[account(mut)]
+        pub route_plan: Option<Box<Account<'info, dot::program::RoutePlan>>>,
+    }
+
+    pub fn update_items_location(
+        ctx: Context<UpdateItemsLocation>,
+        coordinates_class: Coordinates,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let device = SeahorseSigner {
+            account: &ctx.accounts.device,
+            programs: &programs_map,
+        };
+
+        let device_authorization = dot::program::DeviceAuthorization::load(
+            &mut ctx.accounts.device_authorization,
+            &programs_map,
+        );
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let route_plan = ctx
+            .accounts
+            .route_plan
+            .as_mut()
+            .map(|account| dot::program::RoutePlan::load(account, &programs_map));
+
+        update_items_location_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            device.clone(),
+            device_authorization.clone(),
+            items.clone(),
+            route_plan.clone(),
+            coordinates_class,
+        );
+
+        dot::program::DeviceAuthorization::store(device_authorization);
+
+        dot::program::Items::store(items);
+
+        if let Some(route_plan) = route_plan {
+            dot::program::RoutePlan::store(route_plan);
+        }
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (scan_time : i64 , coordinates_class: Coordinates , recent_slot : u64)]
+    pub struct VerifyTagScan<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        #[doc = "CHECK: Instructions sysvar, checked by address."]
+        #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+        pub instructions: UncheckedAccount<'info>,
+        #[doc = "CHECK: SlotHashes sysvar, checked by address."]
+        #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+        pub slot_hashes: UncheckedAccount<'info>,
+    }
+
+    pub fn verify_tag_scan(
+        ctx: Context<VerifyTagScan>,
+        scan_time: i64,
+        coordinates_class: Coordinates,
+        recent_slot: u64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let instructions = ctx.accounts.instructions.to_account_info();
+        let slot_hashes = ctx.accounts.slot_hashes.to_account_info();
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        verify_tag_scan_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            items.clone(),
+            instructions,
+            slot_hashes,
+            scan_time,
             coordinates_class,
+            recent_slot,
+        );
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (handoff_receipt_class : HandoffReceipt)]
+    pub struct TransferItemsWithReceipt<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
+        #[account(mut)]
+        pub old_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub new_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub organization: Option<Box<Account<'info, dot::program::Organization>>>,
+        #[account()]
+        pub new_depot_accreditation_authority: Option<Box<Account<'info, dot::program::AccreditationAuthority>>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        #[doc = "CHECK: Instructions sysvar, checked by address."]
+        #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+        pub instructions: UncheckedAccount<'info>,
+    }
+
+    pub fn transfer_items_with_receipt(
+        ctx: Context<TransferItemsWithReceipt>,
+        handoff_receipt_class: HandoffReceipt,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules =
+            dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map);
+
+        let instructions = ctx.accounts.instructions.to_account_info();
+        let old_depot = dot::program::Depot::load(&mut ctx.accounts.old_depot, &programs_map);
+        let new_depot = dot::program::Depot::load(&mut ctx.accounts.new_depot, &programs_map);
+
+        let organization = ctx
+            .accounts
+            .organization
+            .as_mut()
+            .map(|account| dot::program::Organization::load(account, &programs_map));
+        let new_depot_accreditation_authority = ctx
+            .accounts
+            .new_depot_accreditation_authority
+            .as_mut()
+            .map(|account| dot::program::AccreditationAuthority::load(account, &programs_map));
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        transfer_items_with_receipt_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            transfer_rules.clone(),
+            old_depot.clone(),
+            new_depot.clone(),
+            organization.clone(),
+            new_depot_accreditation_authority.clone(),
+            items.clone(),
+            instructions,
+            handoff_receipt_class,
         );
 
+        dot::program::Depot::store(old_depot);
+
+        dot::program::Depot::store(new_depot);
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (delegate : Pubkey , permissions : u8)]
+    pub struct AddDepotDelegate<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: DepotRole > () + 8 , payer = payer , seeds = [depot . key () . as_ref () , delegate . as_ref () , "depot_role" . as_bytes () . as_ref ()] , bump)]
+        pub depot_role: Box<Account<'info, dot::program::DepotRole>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn add_depot_delegate(
+        ctx: Context<AddDepotDelegate>,
+        delegate: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let depot_role = Empty {
+            account: dot::program::DepotRole::load(&mut ctx.accounts.depot_role, &programs_map),
+            bump: Some(ctx.bumps.depot_role),
+        };
+
+        add_depot_delegate_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            depot_role.clone(),
+            delegate,
+            permissions,
+        );
+
+        dot::program::Depot::store(depot);
+
+        dot::program::DepotRole::store(depot_role.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct RemoveDepotDelegate<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account(mut, close = payer)]
+        pub depot_role: Box<Account<'info, dot::program::DepotRole>>,
+    }
+
+    pub fn remove_depot_delegate(ctx: Context<RemoveDepotDelegate>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let depot_role = dot::program::DepotRole::load(&mut ctx.accounts.depot_role, &programs_map);
+
+        remove_depot_delegate_handler(
+            payer.clone(),
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            depot_role.clone(),
+        );
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (name_array: [u16; 32] , info_array: [u16; 256] , coordinates_class: Coordinates)]
+    pub struct UpdateDepot<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn update_depot(
+        ctx: Context<UpdateDepot>,
+        name_array: [u16; 32],
+        info_array: [u16; 256],
+        coordinates_class: Coordinates,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+
+        update_depot_handler(
+            payer.clone(),
+            config.clone(),
+            owner.clone(),
+            depot.clone(),
+            name_array,
+            info_array,
+            coordinates_class,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (new_owner : Pubkey)]
+    pub struct ChangeDepotOwner<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn change_depot_owner(ctx: Context<ChangeDepotOwner>, new_owner: Pubkey) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+
+        change_depot_owner_handler(
+            payer.clone(),
+            config.clone(),
+            owner.clone(),
+            depot.clone(),
+            new_owner,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (owners : [Pubkey ; 10] , owner_count : u8 , threshold : u8 , seed_random : u128)]
+    pub struct CreateMultisig<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub creator: Signer<'info>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Multisig > () + 8 , payer = payer , seeds = [creator . key () . as_ref () , "multisig" . as_bytes () . as_ref () , seed_random . to_le_bytes () . as_ref ()] , bump)]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        owners: [Pubkey; 10],
+        owner_count: u8,
+        threshold: u8,
+        seed_random: u128,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let creator = SeahorseSigner {
+            account: &ctx.accounts.creator,
+            programs: &programs_map,
+        };
+
+        let multisig = Empty {
+            account: dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map),
+            bump: Some(ctx.bumps.multisig),
+        };
+
+        create_multisig_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            creator.clone(),
+            multisig.clone(),
+            owners,
+            owner_count,
+            threshold,
+            seed_random,
+        );
+
+        dot::program::Multisig::store(multisig.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (action : ProposalAction)]
+    pub struct ProposeDepotAction<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub proposer: Signer<'info>,
+        #[account(mut)]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: MultisigProposal > () + 8 , payer = payer , seeds = [multisig . key () . as_ref () , "proposal" . as_bytes () . as_ref () , multisig . proposal_count . to_le_bytes () . as_ref ()] , bump)]
+        pub proposal: Box<Account<'info, dot::program::MultisigProposal>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn propose_depot_action(
+        ctx: Context<ProposeDepotAction>,
+        action: ProposalAction,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let proposer = SeahorseSigner {
+            account: &ctx.accounts.proposer,
+            programs: &programs_map,
+        };
+
+        let multisig = dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map);
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let proposal = Empty {
+            account: dot::program::MultisigProposal::load(&mut ctx.accounts.proposal, &programs_map),
+            bump: Some(ctx.bumps.proposal),
+        };
+
+        propose_depot_action_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            proposer.clone(),
+            multisig.clone(),
+            depot.clone(),
+            proposal.clone(),
+            action,
+        );
+
+        dot::program::Multisig::store(multisig);
+
+        dot::program::Depot::store(depot);
+
+        dot::program::MultisigProposal::store(proposal.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct ApproveMultisigProposal<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        #[account(mut)]
+        pub proposal: Box<Account<'info, dot::program::MultisigProposal>>,
+    }
+
+    pub fn approve_multisig_proposal(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let multisig = dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map);
+        let proposal = dot::program::MultisigProposal::load(&mut ctx.accounts.proposal, &programs_map);
+
+        approve_multisig_proposal_handler(
+            payer.clone(),
+            config.clone(),
+            owner.clone(),
+            multisig.clone(),
+            proposal.clone(),
+        );
+
+        dot::program::Multisig::store(multisig);
+
+        dot::program::MultisigProposal::store(proposal);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct ExecuteDepotProposal<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        #[account(mut)]
+        pub proposal: Box<Account<'info, dot::program::MultisigProposal>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn execute_depot_proposal(ctx: Context<ExecuteDepotProposal>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let multisig = dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map);
+        let proposal = dot::program::MultisigProposal::load(&mut ctx.accounts.proposal, &programs_map);
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+
+        execute_depot_proposal_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            multisig.clone(),
+            proposal.clone(),
+            depot.clone(),
+        );
+
+        dot::program::Multisig::store(multisig);
+
+        dot::program::MultisigProposal::store(proposal);
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (override_compliance : bool)]
+    pub struct TransferItemsToMultisigDepot<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
+        #[account(mut)]
+        pub old_depot_owner: Signer<'info>,
+        #[account(mut)]
+        pub old_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub new_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub old_depot_owner_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub old_depot_owner_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account()]
+        pub organization: Option<Box<Account<'info, dot::program::Organization>>>,
+        #[account(mut)]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        #[account(mut)]
+        pub proposal: Box<Account<'info, dot::program::MultisigProposal>>,
+        #[account()]
+        pub new_depot_accreditation_authority: Option<Box<Account<'info, dot::program::AccreditationAuthority>>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn transfer_items_to_multisig_depot(
+        ctx: Context<TransferItemsToMultisigDepot>,
+        override_compliance: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules =
+            dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map);
+
+        let old_depot_owner = SeahorseSigner {
+            account: &ctx.accounts.old_depot_owner,
+            programs: &programs_map,
+        };
+
+        let old_depot = dot::program::Depot::load(&mut ctx.accounts.old_depot, &programs_map);
+        let new_depot = dot::program::Depot::load(&mut ctx.accounts.new_depot, &programs_map);
+        let old_depot_owner_role = ctx
+            .accounts
+            .old_depot_owner_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let old_depot_owner_hub_depot = ctx
+            .accounts
+            .old_depot_owner_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let organization = ctx
+            .accounts
+            .organization
+            .as_mut()
+            .map(|account| dot::program::Organization::load(account, &programs_map));
+
+        let multisig = dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map);
+        let proposal = dot::program::MultisigProposal::load(&mut ctx.accounts.proposal, &programs_map);
+        let new_depot_accreditation_authority = ctx
+            .accounts
+            .new_depot_accreditation_authority
+            .as_mut()
+            .map(|account| dot::program::AccreditationAuthority::load(account, &programs_map));
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        transfer_items_to_multisig_depot_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            transfer_rules.clone(),
+            old_depot_owner.clone(),
+            old_depot.clone(),
+            new_depot.clone(),
+            old_depot_owner_role.clone(),
+            old_depot_owner_hub_depot.clone(),
+            organization.clone(),
+            multisig.clone(),
+            proposal.clone(),
+            new_depot_accreditation_authority.clone(),
+            items.clone(),
+            override_compliance,
+        );
+
+        dot::program::Depot::store(old_depot);
+
+        dot::program::Depot::store(new_depot);
+
+        dot::program::MultisigProposal::store(proposal);
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct TransferItemsFromMultisigDepot<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
+        #[account(mut)]
+        pub new_depot_owner: Option<Signer<'info>>,
+        #[account(mut)]
+        pub old_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub new_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub new_depot_owner_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub new_depot_owner_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account()]
+        pub organization: Option<Box<Account<'info, dot::program::Organization>>>,
+        #[account(mut)]
+        pub partner_agreement: Option<Box<Account<'info, dot::program::PartnerAgreement>>>,
+        #[account(mut)]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        #[account(mut)]
+        pub proposal: Box<Account<'info, dot::program::MultisigProposal>>,
+        #[account()]
+        pub new_depot_accreditation_authority: Option<Box<Account<'info, dot::program::AccreditationAuthority>>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn transfer_items_from_multisig_depot(
+        ctx: Context<TransferItemsFromMultisigDepot>,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules =
+            dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map);
+
+        let new_depot_owner = ctx
+            .accounts
+            .new_depot_owner
+            .as_ref()
+            .map(|account| SeahorseSigner {
+                account,
+                programs: &programs_map,
+            });
+
+        let old_depot = dot::program::Depot::load(&mut ctx.accounts.old_depot, &programs_map);
+        let new_depot = dot::program::Depot::load(&mut ctx.accounts.new_depot, &programs_map);
+        let new_depot_owner_role = ctx
+            .accounts
+            .new_depot_owner_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let new_depot_owner_hub_depot = ctx
+            .accounts
+            .new_depot_owner_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let organization = ctx
+            .accounts
+            .organization
+            .as_mut()
+            .map(|account| dot::program::Organization::load(account, &programs_map));
+
+        let partner_agreement = ctx
+            .accounts
+            .partner_agreement
+            .as_mut()
+            .map(|account| dot::program::PartnerAgreement::load(account, &programs_map));
+
+        let multisig = dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map);
+        let proposal = dot::program::MultisigProposal::load(&mut ctx.accounts.proposal, &programs_map);
+        let new_depot_accreditation_authority = ctx
+            .accounts
+            .new_depot_accreditation_authority
+            .as_mut()
+            .map(|account| dot::program::AccreditationAuthority::load(account, &programs_map));
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        transfer_items_from_multisig_depot_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            transfer_rules.clone(),
+            new_depot_owner.clone(),
+            old_depot.clone(),
+            new_depot.clone(),
+            new_depot_owner_role.clone(),
+            new_depot_owner_hub_depot.clone(),
+            organization.clone(),
+            partner_agreement.clone(),
+            multisig.clone(),
+            proposal.clone(),
+            new_depot_accreditation_authority.clone(),
+            items.clone(),
+        );
+
+        dot::program::Depot::store(old_depot);
+
+        dot::program::Depot::store(new_depot);
+
+        dot::program::MultisigProposal::store(proposal);
+
+        dot::program::Items::store(items);
+
+        if let Some(partner_agreement) = partner_agreement {
+            dot::program::PartnerAgreement::store(partner_agreement);
+        }
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (delegate : Pubkey)]
+    pub struct GrantDepotRoleByMultisig<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account()]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        #[account(mut)]
+        pub proposal: Box<Account<'info, dot::program::MultisigProposal>>,
+        #[account()]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: DepotRole > () + 8 , payer = payer , seeds = [depot . key () . as_ref () , delegate . as_ref () , "depot_role" . as_bytes () . as_ref ()] , bump)]
+        pub depot_role: Box<Account<'info, dot::program::DepotRole>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn grant_depot_role_by_multisig(
+        ctx: Context<GrantDepotRoleByMultisig>,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let multisig = dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map);
+        let proposal = dot::program::MultisigProposal::load(&mut ctx.accounts.proposal, &programs_map);
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let depot_role = Empty {
+            account: dot::program::DepotRole::load(&mut ctx.accounts.depot_role, &programs_map),
+            bump: Some(ctx.bumps.depot_role),
+        };
+
+        grant_depot_role_by_multisig_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            multisig.clone(),
+            proposal.clone(),
+            depot.clone(),
+            depot_role.clone(),
+            delegate,
+        );
+
+        dot::program::MultisigProposal::store(proposal);
+
+        dot::program::DepotRole::store(depot_role.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct RevokeDepotRoleByMultisig<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account()]
+        pub multisig: Box<Account<'info, dot::program::Multisig>>,
+        #[account(mut)]
+        pub proposal: Box<Account<'info, dot::program::MultisigProposal>>,
+        #[account()]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut, close = payer)]
+        pub depot_role: Box<Account<'info, dot::program::DepotRole>>,
+    }
+
+    pub fn revoke_depot_role_by_multisig(ctx: Context<RevokeDepotRoleByMultisig>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let multisig = dot::program::Multisig::load(&mut ctx.accounts.multisig, &programs_map);
+        let proposal = dot::program::MultisigProposal::load(&mut ctx.accounts.proposal, &programs_map);
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let depot_role = dot::program::DepotRole::load(&mut ctx.accounts.depot_role, &programs_map);
+
+        revoke_depot_role_by_multisig_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            multisig.clone(),
+            proposal.clone(),
+            depot.clone(),
+            depot_role.clone(),
+        );
+
+        dot::program::MultisigProposal::store(proposal);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (name_array: [u16; 32] , seed_random : u128)]
+    pub struct CreateOrganization<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub creator: Signer<'info>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Organization > () + 8 , payer = payer , seeds = [creator . key () . as_ref () , "organization" . as_bytes () . as_ref () , seed_random . to_le_bytes () . as_ref ()] , bump)]
+        pub organization: Box<Account<'info, dot::program::Organization>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn create_organization(
+        ctx: Context<CreateOrganization>,
+        name_array: [u16; 32],
+        seed_random: u128,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let creator = SeahorseSigner {
+            account: &ctx.accounts.creator,
+            programs: &programs_map,
+        };
+
+        let organization = Empty {
+            account: dot::program::Organization::load(&mut ctx.accounts.organization, &programs_map),
+            bump: Some(ctx.bumps.organization),
+        };
+
+        create_organization_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            creator.clone(),
+            organization.clone(),
+            name_array,
+            seed_random,
+        );
+
+        dot::program::Organization::store(organization.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (new_admin : Pubkey)]
+    pub struct AddOrganizationAdmin<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        #[account(mut)]
+        pub organization: Box<Account<'info, dot::program::Organization>>,
+    }
+
+    pub fn add_organization_admin(
+        ctx: Context<AddOrganizationAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let organization =
+            dot::program::Organization::load(&mut ctx.accounts.organization, &programs_map);
+
+        add_organization_admin_handler(
+            payer.clone(),
+            config.clone(),
+            admin.clone(),
+            organization.clone(),
+            new_admin,
+        );
+
+        dot::program::Organization::store(organization);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (removed_admin : Pubkey)]
+    pub struct RemoveOrganizationAdmin<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        #[account(mut)]
+        pub organization: Box<Account<'info, dot::program::Organization>>,
+    }
+
+    pub fn remove_organization_admin(
+        ctx: Context<RemoveOrganizationAdmin>,
+        removed_admin: Pubkey,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let organization =
+            dot::program::Organization::load(&mut ctx.accounts.organization, &programs_map);
+
+        remove_organization_admin_handler(
+            payer.clone(),
+            config.clone(),
+            admin.clone(),
+            organization.clone(),
+            removed_admin,
+        );
+
+        dot::program::Organization::store(organization);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (single_signer_internal_transfers : bool , require_verified_destination : bool)]
+    pub struct SetOrganizationPolicy<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        #[account(mut)]
+        pub organization: Box<Account<'info, dot::program::Organization>>,
+    }
+
+    pub fn set_organization_policy(
+        ctx: Context<SetOrganizationPolicy>,
+        single_signer_internal_transfers: bool,
+        require_verified_destination: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let organization =
+            dot::program::Organization::load(&mut ctx.accounts.organization, &programs_map);
+
+        set_organization_policy_handler(
+            payer.clone(),
+            config.clone(),
+            admin.clone(),
+            organization.clone(),
+            single_signer_internal_transfers,
+            require_verified_destination,
+        );
+
+        dot::program::Organization::store(organization);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct AddDepotToOrganization<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        #[account(mut)]
+        pub depot_owner: Signer<'info>,
+        #[account(mut)]
+        pub organization: Box<Account<'info, dot::program::Organization>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: OrganizationDepot > () + 8 , payer = payer , seeds = [organization . key () . as_ref () , "org_depot" . as_bytes () . as_ref () , organization . depot_count . to_le_bytes () . as_ref ()] , bump)]
+        pub organization_depot: Box<Account<'info, dot::program::OrganizationDepot>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn add_depot_to_organization(ctx: Context<AddDepotToOrganization>) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let depot_owner = SeahorseSigner {
+            account: &ctx.accounts.depot_owner,
+            programs: &programs_map,
+        };
+
+        let organization =
+            dot::program::Organization::load(&mut ctx.accounts.organization, &programs_map);
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let organization_depot = Empty {
+            account: dot::program::OrganizationDepot::load(
+                &mut ctx.accounts.organization_depot,
+                &programs_map,
+            ),
+            bump: Some(ctx.bumps.organization_depot),
+        };
+
+        add_depot_to_organization_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            admin.clone(),
+            depot_owner.clone(),
+            organization.clone(),
+            depot.clone(),
+            organization_depot.clone(),
+        );
+
+        dot::program::Organization::store(organization);
+
+        dot::program::Depot::store(depot);
+
+        dot::program::OrganizationDepot::store(organization_depot.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct RemoveDepotFromOrganization<'info> {
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub organization: Box<Account<'info, dot::program::Organization>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut, close = payer)]
+        pub last_organization_depot: Box<Account<'info, dot::program::OrganizationDepot>>,
+        #[account(mut)]
+        pub organization_depot: Option<Box<Account<'info, dot::program::OrganizationDepot>>>,
+        #[account(mut)]
+        pub last_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn remove_depot_from_organization(
+        ctx: Context<RemoveDepotFromOrganization>,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let organization =
+            dot::program::Organization::load(&mut ctx.accounts.organization, &programs_map);
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let last_organization_depot = dot::program::OrganizationDepot::load(
+            &mut ctx.accounts.last_organization_depot,
+            &programs_map,
+        );
+
+        let organization_depot = ctx
+            .accounts
+            .organization_depot
+            .as_mut()
+            .map(|account| dot::program::OrganizationDepot::load(account, &programs_map));
+
+        let last_depot = ctx
+            .accounts
+            .last_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        remove_depot_from_organization_handler(
+            payer.clone(),
+            config.clone(),
+            authority.clone(),
+            organization.clone(),
+            depot.clone(),
+            last_organization_depot.clone(),
+            organization_depot.clone(),
+            last_depot.clone(),
+        );
+
+        dot::program::Organization::store(organization);
+
+        dot::program::Depot::store(depot);
+
+        if let Some(organization_depot) = organization_depot {
+            dot::program::OrganizationDepot::store(organization_depot);
+        }
+
+        if let Some(last_depot) = last_depot {
+            dot::program::Depot::store(last_depot);
+        }
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (max_speed : f64 , depot_fee : u64 , items_fee : u64)]
+    pub struct InitConfig<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Config > () + 8 , payer = payer , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (seeds = [crate :: ID . as_ref ()] , bump , seeds :: program = anchor_lang :: solana_program :: bpf_loader_upgradeable :: ID , constraint = program_data . upgrade_authority_address == Some (admin . key ()))]
+        pub program_data: Box<Account<'info, ProgramData>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn init_config(
+        ctx: Context<InitConfig>,
+        max_speed: f64,
+        depot_fee: u64,
+        items_fee: u64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = Empty {
+            account: dot::program::Config::load(&mut ctx.accounts.config, &programs_map),
+            bump: Some(ctx.bumps.config),
+        };
+
+        init_config_handler(
+            clock.clone(),
+            payer.clone(),
+            admin.clone(),
+            config.clone(),
+            max_speed,
+            depot_fee,
+            items_fee,
+        );
+
+        dot::program::Config::store(config.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (paused : bool)]
+    pub struct SetPaused<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (mut , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        set_paused_handler(admin.clone(), config.clone(), paused);
+
+        dot::program::Config::store(config);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (max_speed : f64 , depot_fee : u64 , items_fee : u64)]
+    pub struct UpdateConfig<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (mut , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        max_speed: f64,
+        depot_fee: u64,
+        items_fee: u64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        update_config_handler(admin.clone(), config.clone(), max_speed, depot_fee, items_fee);
+
+        dot::program::Config::store(config);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (new_admin : Pubkey)]
+    pub struct ProposeConfigAdmin<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (mut , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+    }
+
+    pub fn propose_config_admin(ctx: Context<ProposeConfigAdmin>, new_admin: Pubkey) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        propose_config_admin_handler(admin.clone(), config.clone(), new_admin);
+
+        dot::program::Config::store(config);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct AcceptConfigAdmin<'info> {
+        #[account(mut)]
+        pub new_admin: Signer<'info>,
+        # [account (mut , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+    }
+
+    pub fn accept_config_admin(ctx: Context<AcceptConfigAdmin>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let new_admin = SeahorseSigner {
+            account: &ctx.accounts.new_admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        accept_config_admin_handler(new_admin.clone(), config.clone());
+
+        dot::program::Config::store(config);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (amount : u64)]
+    pub struct WithdrawFees<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (mut , seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[doc = "CHECK: Any account may receive the withdrawn fees."]
+        #[account(mut)]
+        pub receiver: UncheckedAccount<'info>,
+    }
+
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let receiver = ctx.accounts.receiver.to_account_info();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        withdraw_fees_handler(admin.clone(), config.clone(), receiver, amount);
+
+        dot::program::Config::store(config);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (authority : Pubkey , name_array: [u16; 32])]
+    pub struct RegisterAccreditationAuthority<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: AccreditationAuthority > () + 8 , payer = payer , seeds = [authority . as_ref () , "accreditor" . as_bytes () . as_ref ()] , bump)]
+        pub accreditation_authority: Box<Account<'info, dot::program::AccreditationAuthority>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn register_accreditation_authority(
+        ctx: Context<RegisterAccreditationAuthority>,
+        authority: Pubkey,
+        name_array: [u16; 32],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let accreditation_authority = Empty {
+            account: dot::program::AccreditationAuthority::load(
+                &mut ctx.accounts.accreditation_authority,
+                &programs_map,
+            ),
+            bump: Some(ctx.bumps.accreditation_authority),
+        };
+
+        register_accreditation_authority_handler(
+            clock.clone(),
+            payer.clone(),
+            admin.clone(),
+            config.clone(),
+            accreditation_authority.clone(),
+            authority,
+            name_array,
+        );
+
+        dot::program::AccreditationAuthority::store(accreditation_authority.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (active : bool)]
+    pub struct SetAccreditationAuthorityActive<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub accreditation_authority: Box<Account<'info, dot::program::AccreditationAuthority>>,
+    }
+
+    pub fn set_accreditation_authority_active(
+        ctx: Context<SetAccreditationAuthorityActive>,
+        active: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let accreditation_authority = dot::program::AccreditationAuthority::load(
+            &mut ctx.accounts.accreditation_authority,
+            &programs_map,
+        );
+
+        set_accreditation_authority_active_handler(
+            admin.clone(),
+            config.clone(),
+            accreditation_authority.clone(),
+            active,
+        );
+
+        dot::program::AccreditationAuthority::store(accreditation_authority);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (verification_expiry : i64 , credential_ref : [u8 ; 32])]
+    pub struct IssueDepotVerification<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account()]
+        pub accreditation_authority: Box<Account<'info, dot::program::AccreditationAuthority>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn issue_depot_verification(
+        ctx: Context<IssueDepotVerification>,
+        verification_expiry: i64,
+        credential_ref: [u8; 32],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let accreditation_authority = dot::program::AccreditationAuthority::load(
+            &mut ctx.accounts.accreditation_authority,
+            &programs_map,
+        );
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+
+        issue_depot_verification_handler(
+            clock.clone(),
+            config.clone(),
+            authority.clone(),
+            accreditation_authority.clone(),
+            depot.clone(),
+            verification_expiry,
+            credential_ref,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct RevokeDepotVerification<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account()]
+        pub accreditation_authority: Box<Account<'info, dot::program::AccreditationAuthority>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn revoke_depot_verification(ctx: Context<RevokeDepotVerification>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let accreditation_authority = dot::program::AccreditationAuthority::load(
+            &mut ctx.accounts.accreditation_authority,
+            &programs_map,
+        );
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+
+        revoke_depot_verification_handler(
+            config.clone(),
+            authority.clone(),
+            accreditation_authority.clone(),
+            depot.clone(),
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (require_verified_destination : bool)]
+    pub struct SetItemsVerificationPolicy<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn set_items_verification_policy(
+        ctx: Context<SetItemsVerificationPolicy>,
+        require_verified_destination: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        set_items_verification_policy_handler(
+            config.clone(),
+            owner.clone(),
+            items.clone(),
+            require_verified_destination,
+        );
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (new_owner : Pubkey)]
+    pub struct ChangeItemsOwner<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn change_items_owner(ctx: Context<ChangeItemsOwner>, new_owner: Pubkey) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        change_items_owner_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            owner.clone(),
+            items.clone(),
+            new_owner,
+        );
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (regulator : Pubkey , name_array: [u16; 32])]
+    pub struct RegisterRegulator<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Regulator > () + 8 , payer = payer , seeds = [regulator . as_ref () , "regulator" . as_bytes () . as_ref ()] , bump)]
+        pub regulator_account: Box<Account<'info, dot::program::Regulator>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn register_regulator(
+        ctx: Context<RegisterRegulator>,
+        regulator: Pubkey,
+        name_array: [u16; 32],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let regulator_account = Empty {
+            account: dot::program::Regulator::load(&mut ctx.accounts.regulator_account, &programs_map),
+            bump: Some(ctx.bumps.regulator_account),
+        };
+
+        register_regulator_handler(
+            clock.clone(),
+            payer.clone(),
+            admin.clone(),
+            config.clone(),
+            regulator_account.clone(),
+            regulator,
+            name_array,
+        );
+
+        dot::program::Regulator::store(regulator_account.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (active : bool)]
+    pub struct SetRegulatorActive<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub regulator_account: Box<Account<'info, dot::program::Regulator>>,
+    }
+
+    pub fn set_regulator_active(ctx: Context<SetRegulatorActive>, active: bool) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let regulator_account =
+            dot::program::Regulator::load(&mut ctx.accounts.regulator_account, &programs_map);
+
+        set_regulator_active_handler(admin.clone(), config.clone(), regulator_account.clone(), active);
+
+        dot::program::Regulator::store(regulator_account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (reason_code : u16)]
+    pub struct FreezeItems<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub regulator: Signer<'info>,
+        #[account()]
+        pub regulator_account: Box<Account<'info, dot::program::Regulator>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: FreezeRecord > () + 8 , payer = payer , seeds = [items . key () . as_ref () , "freeze_record" . as_bytes () . as_ref () , items . freeze_record_count . to_le_bytes () . as_ref ()] , bump)]
+        pub freeze_record: Box<Account<'info, dot::program::FreezeRecord>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn freeze_items(ctx: Context<FreezeItems>, reason_code: u16) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let regulator = SeahorseSigner {
+            account: &ctx.accounts.regulator,
+            programs: &programs_map,
+        };
+
+        let regulator_account =
+            dot::program::Regulator::load(&mut ctx.accounts.regulator_account, &programs_map);
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let freeze_record = Empty {
+            account: dot::program::FreezeRecord::load(&mut ctx.accounts.freeze_record, &programs_map),
+            bump: Some(ctx.bumps.freeze_record),
+        };
+
+        freeze_items_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            regulator.clone(),
+            regulator_account.clone(),
+            items.clone(),
+            freeze_record.clone(),
+            reason_code,
+        );
+
+        dot::program::Items::store(items);
+
+        dot::program::FreezeRecord::store(freeze_record.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (reason_code : u16)]
+    pub struct UnfreezeItems<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub regulator: Signer<'info>,
+        #[account()]
+        pub regulator_account: Box<Account<'info, dot::program::Regulator>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: FreezeRecord > () + 8 , payer = payer , seeds = [items . key () . as_ref () , "freeze_record" . as_bytes () . as_ref () , items . freeze_record_count . to_le_bytes () . as_ref ()] , bump)]
+        pub freeze_record: Box<Account<'info, dot::program::FreezeRecord>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn unfreeze_items(ctx: Context<UnfreezeItems>, reason_code: u16) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let regulator = SeahorseSigner {
+            account: &ctx.accounts.regulator,
+            programs: &programs_map,
+        };
+
+        let regulator_account =
+            dot::program::Regulator::load(&mut ctx.accounts.regulator_account, &programs_map);
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let freeze_record = Empty {
+            account: dot::program::FreezeRecord::load(&mut ctx.accounts.freeze_record, &programs_map),
+            bump: Some(ctx.bumps.freeze_record),
+        };
+
+        unfreeze_items_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            regulator.clone(),
+            regulator_account.clone(),
+            items.clone(),
+            freeze_record.clone(),
+            reason_code,
+        );
+
+        dot::program::Items::store(items);
+
+        dot::program::FreezeRecord::store(freeze_record.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (inspector : Pubkey , name_array: [u16; 32])]
+    pub struct RegisterInspector<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Inspector > () + 8 , payer = payer , seeds = [inspector . as_ref () , "inspector" . as_bytes () . as_ref ()] , bump)]
+        pub inspector_account: Box<Account<'info, dot::program::Inspector>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn register_inspector(
+        ctx: Context<RegisterInspector>,
+        inspector: Pubkey,
+        name_array: [u16; 32],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let inspector_account = Empty {
+            account: dot::program::Inspector::load(&mut ctx.accounts.inspector_account, &programs_map),
+            bump: Some(ctx.bumps.inspector_account),
+        };
+
+        register_inspector_handler(
+            clock.clone(),
+            payer.clone(),
+            admin.clone(),
+            config.clone(),
+            inspector_account.clone(),
+            inspector,
+            name_array,
+        );
+
+        dot::program::Inspector::store(inspector_account.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (active : bool)]
+    pub struct SetInspectorActive<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub inspector_account: Box<Account<'info, dot::program::Inspector>>,
+    }
+
+    pub fn set_inspector_active(ctx: Context<SetInspectorActive>, active: bool) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let inspector_account =
+            dot::program::Inspector::load(&mut ctx.accounts.inspector_account, &programs_map);
+
+        set_inspector_active_handler(admin.clone(), config.clone(), inspector_account.clone(), active);
+
+        dot::program::Inspector::store(inspector_account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (verdict : InspectionVerdict , findings_hash : [u8 ; 32])]
+    pub struct InspectItems<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub inspector: Signer<'info>,
+        #[account()]
+        pub inspector_account: Box<Account<'info, dot::program::Inspector>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Inspection > () + 8 , payer = payer , seeds = [items . key () . as_ref () , "inspection" . as_bytes () . as_ref () , items . inspection_count . to_le_bytes () . as_ref ()] , bump)]
+        pub inspection: Box<Account<'info, dot::program::Inspection>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn inspect_items(
+        ctx: Context<InspectItems>,
+        verdict: InspectionVerdict,
+        findings_hash: [u8; 32],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let inspector = SeahorseSigner {
+            account: &ctx.accounts.inspector,
+            programs: &programs_map,
+        };
+
+        let inspector_account =
+            dot::program::Inspector::load(&mut ctx.accounts.inspector_account, &programs_map);
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let inspection = Empty {
+            account: dot::program::Inspection::load(&mut ctx.accounts.inspection, &programs_map),
+            bump: Some(ctx.bumps.inspection),
+        };
+
+        inspect_items_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            inspector.clone(),
+            inspector_account.clone(),
+            items.clone(),
+            inspection.clone(),
+            verdict,
+            findings_hash,
+        );
+
+        dot::program::Items::store(items);
+
+        dot::program::Inspection::store(inspection.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (require_inspection_before_dispatch : bool)]
+    pub struct SetDepotInspectionPolicy<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn set_depot_inspection_policy(
+        ctx: Context<SetDepotInspectionPolicy>,
+        require_inspection_before_dispatch: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        set_depot_inspection_policy_handler(
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            require_inspection_before_dispatch,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (certifier : Pubkey , name_array: [u16; 32])]
+    pub struct RegisterCertifier<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Certifier > () + 8 , payer = payer , seeds = [certifier . as_ref () , "certifier" . as_bytes () . as_ref ()] , bump)]
+        pub certifier_account: Box<Account<'info, dot::program::Certifier>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn register_certifier(
+        ctx: Context<RegisterCertifier>,
+        certifier: Pubkey,
+        name_array: [u16; 32],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let certifier_account = Empty {
+            account: dot::program::Certifier::load(&mut ctx.accounts.certifier_account, &programs_map),
+            bump: Some(ctx.bumps.certifier_account),
+        };
+
+        register_certifier_handler(
+            clock.clone(),
+            payer.clone(),
+            admin.clone(),
+            config.clone(),
+            certifier_account.clone(),
+            certifier,
+            name_array,
+        );
+
+        dot::program::Certifier::store(certifier_account.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (active : bool)]
+    pub struct SetCertifierActive<'info> {
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub certifier_account: Box<Account<'info, dot::program::Certifier>>,
+    }
+
+    pub fn set_certifier_active(ctx: Context<SetCertifierActive>, active: bool) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let certifier_account =
+            dot::program::Certifier::load(&mut ctx.accounts.certifier_account, &programs_map);
+
+        set_certifier_active_handler(admin.clone(), config.clone(), certifier_account.clone(), active);
+
+        dot::program::Certifier::store(certifier_account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (scheme : [u8 ; 16] , valid_from : i64 , valid_until : i64)]
+    pub struct CertifyItems<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub certifier: Signer<'info>,
+        #[account()]
+        pub certifier_account: Box<Account<'info, dot::program::Certifier>>,
+        #[account()]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Certification > () + 8 , payer = payer , seeds = [items . key () . as_ref () , "certification" . as_bytes () . as_ref () , scheme . as_ref () , certifier . key () . as_ref ()] , bump)]
+        pub certification: Box<Account<'info, dot::program::Certification>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn certify_items(
+        ctx: Context<CertifyItems>,
+        scheme: [u8; 16],
+        valid_from: i64,
+        valid_until: i64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let certifier = SeahorseSigner {
+            account: &ctx.accounts.certifier,
+            programs: &programs_map,
+        };
+
+        let certifier_account =
+            dot::program::Certifier::load(&mut ctx.accounts.certifier_account, &programs_map);
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let certification = Empty {
+            account: dot::program::Certification::load(&mut ctx.accounts.certification, &programs_map),
+            bump: Some(ctx.bumps.certification),
+        };
+
+        certify_items_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            certifier.clone(),
+            certifier_account.clone(),
+            items.clone(),
+            certification.clone(),
+            scheme,
+            valid_from,
+            valid_until,
+        );
+
+        dot::program::Certification::store(certification.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (scheme : [u8 ; 16] , valid_from : i64 , valid_until : i64)]
+    pub struct CertifyDepot<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub certifier: Signer<'info>,
+        #[account()]
+        pub certifier_account: Box<Account<'info, dot::program::Certifier>>,
+        #[account()]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Certification > () + 8 , payer = payer , seeds = [depot . key () . as_ref () , "certification" . as_bytes () . as_ref () , scheme . as_ref () , certifier . key () . as_ref ()] , bump)]
+        pub certification: Box<Account<'info, dot::program::Certification>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn certify_depot(
+        ctx: Context<CertifyDepot>,
+        scheme: [u8; 16],
+        valid_from: i64,
+        valid_until: i64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let certifier = SeahorseSigner {
+            account: &ctx.accounts.certifier,
+            programs: &programs_map,
+        };
+
+        let certifier_account =
+            dot::program::Certifier::load(&mut ctx.accounts.certifier_account, &programs_map);
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let certification = Empty {
+            account: dot::program::Certification::load(&mut ctx.accounts.certification, &programs_map),
+            bump: Some(ctx.bumps.certification),
+        };
+
+        certify_depot_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            certifier.clone(),
+            certifier_account.clone(),
+            depot.clone(),
+            certification.clone(),
+            scheme,
+            valid_from,
+            valid_until,
+        );
+
+        dot::program::Certification::store(certification.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct RevokeCertification<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub certifier: Signer<'info>,
+        #[account(mut)]
+        pub certification: Box<Account<'info, dot::program::Certification>>,
+    }
+
+    pub fn revoke_certification(ctx: Context<RevokeCertification>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let certifier = SeahorseSigner {
+            account: &ctx.accounts.certifier,
+            programs: &programs_map,
+        };
+
+        let certification =
+            dot::program::Certification::load(&mut ctx.accounts.certification, &programs_map);
+
+        revoke_certification_handler(
+            clock.clone(),
+            config.clone(),
+            certifier.clone(),
+            certification.clone(),
+        );
+
+        dot::program::Certification::store(certification);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (valid_from : i64 , valid_until : i64)]
+    pub struct RenewCertification<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub certifier: Signer<'info>,
+        #[account()]
+        pub certifier_account: Box<Account<'info, dot::program::Certifier>>,
+        #[account(mut)]
+        pub certification: Box<Account<'info, dot::program::Certification>>,
+    }
+
+    pub fn renew_certification(
+        ctx: Context<RenewCertification>,
+        valid_from: i64,
+        valid_until: i64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let certifier = SeahorseSigner {
+            account: &ctx.accounts.certifier,
+            programs: &programs_map,
+        };
+
+        let certifier_account =
+            dot::program::Certifier::load(&mut ctx.accounts.certifier_account, &programs_map);
+
+        let certification =
+            dot::program::Certification::load(&mut ctx.accounts.certification, &programs_map);
+
+        renew_certification_handler(
+            clock.clone(),
+            config.clone(),
+            certifier.clone(),
+            certifier_account.clone(),
+            certification.clone(),
+            valid_from,
+            valid_until,
+        );
+
+        dot::program::Certification::store(certification);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (scheme : [u8 ; 16])]
+    pub struct HasCertification<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account()]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        #[account()]
+        pub certification: Box<Account<'info, dot::program::Certification>>,
+        #[account()]
+        pub certifier_account: Box<Account<'info, dot::program::Certifier>>,
+    }
+
+    pub fn has_certification(ctx: Context<HasCertification>, scheme: [u8; 16]) -> Result<bool> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let certification =
+            dot::program::Certification::load(&mut ctx.accounts.certification, &programs_map);
+
+        let certifier_account =
+            dot::program::Certifier::load(&mut ctx.accounts.certifier_account, &programs_map);
+
+        let certified = has_certification_handler(
+            clock.clone(),
+            items.clone(),
+            certification.clone(),
+            certifier_account.clone(),
+            scheme,
+        );
+
+        return Ok(certified);
+    }
+
+    #[derive(Accounts)]
+    # [instruction (document_type : DocumentType , hash : [u8 ; 32] , uri_array : Option < [u16 ; 128] > , for_transfer : bool)]
+    pub struct AttachDocument<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub signer: Signer<'info>,
+        #[account()]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub signer_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub signer_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Document > () + 8 , payer = payer , seeds = [items . key () . as_ref () , "document" . as_bytes () . as_ref () , items . document_count . to_le_bytes () . as_ref ()] , bump)]
+        pub document: Box<Account<'info, dot::program::Document>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn attach_document(
+        ctx: Context<AttachDocument>,
+        document_type: DocumentType,
+        hash: [u8; 32],
+        uri_array: Option<[u16; 128]>,
+        for_transfer: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let signer = SeahorseSigner {
+            account: &ctx.accounts.signer,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let signer_role = ctx
+            .accounts
+            .signer_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let signer_hub_depot = ctx
+            .accounts
+            .signer_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let document = Empty {
+            account: dot::program::Document::load(&mut ctx.accounts.document, &programs_map),
+            bump: Some(ctx.bumps.document),
+        };
+
+        attach_document_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            signer.clone(),
+            depot.clone(),
+            signer_role.clone(),
+            signer_hub_depot.clone(),
+            items.clone(),
+            document.clone(),
+            document_type,
+            hash,
+            uri_array,
+            for_transfer,
+        );
+
+        dot::program::Items::store(items);
+
+        dot::program::Document::store(document.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (boundary_required_documents : u8)]
+    pub struct SetDepotDocumentPolicy<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn set_depot_document_policy(
+        ctx: Context<SetDepotDocumentPolicy>,
+        boundary_required_documents: u8,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        set_depot_document_policy_handler(
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            boundary_required_documents,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct InitTransferRules<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: TransferRules > () + 8 , payer = payer , seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn init_transfer_rules(ctx: Context<InitTransferRules>) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules = Empty {
+            account: dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map),
+            bump: Some(ctx.bumps.transfer_rules),
+        };
+
+        init_transfer_rules_handler(
+            clock.clone(),
+            payer.clone(),
+            admin.clone(),
+            config.clone(),
+            transfer_rules.clone(),
+        );
+
+        dot::program::TransferRules::store(transfer_rules.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (transfer_rule_class : TransferRule)]
+    pub struct AddTransferRule<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (mut , seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
+    }
+
+    pub fn add_transfer_rule(
+        ctx: Context<AddTransferRule>,
+        transfer_rule_class: TransferRule,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules =
+            dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map);
+
+        add_transfer_rule_handler(
+            clock.clone(),
+            admin.clone(),
+            config.clone(),
+            transfer_rules.clone(),
+            transfer_rule_class,
+        );
+
+        dot::program::TransferRules::store(transfer_rules);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (index : u8)]
+    pub struct RemoveTransferRule<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub admin: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (mut , seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
+    }
+
+    pub fn remove_transfer_rule(ctx: Context<RemoveTransferRule>, index: u8) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let admin = SeahorseSigner {
+            account: &ctx.accounts.admin,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules =
+            dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map);
+
+        remove_transfer_rule_handler(
+            clock.clone(),
+            admin.clone(),
+            config.clone(),
+            transfer_rules.clone(),
+            index,
+        );
+
+        dot::program::TransferRules::store(transfer_rules);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (jurisdiction_code : [u8 ; 6])]
+    pub struct SetDepotJurisdiction<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn set_depot_jurisdiction(
+        ctx: Context<SetDepotJurisdiction>,
+        jurisdiction_code: [u8; 6],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        set_depot_jurisdiction_handler(
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            jurisdiction_code,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (licensed_hazmat_classes : u16)]
+    pub struct SetDepotHazmatLicenses<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account()]
+        pub accreditation_authority: Box<Account<'info, dot::program::AccreditationAuthority>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn set_depot_hazmat_licenses(
+        ctx: Context<SetDepotHazmatLicenses>,
+        licensed_hazmat_classes: u16,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let accreditation_authority = dot::program::AccreditationAuthority::load(
+            &mut ctx.accounts.accreditation_authority,
+            &programs_map,
+        );
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+
+        set_depot_hazmat_licenses_handler(
+            config.clone(),
+            authority.clone(),
+            accreditation_authority.clone(),
+            depot.clone(),
+            licensed_hazmat_classes,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (max_item_count : u64 , max_weight_grams : u64 , max_volume_cm3 : u64)]
+    pub struct SetDepotCapacity<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn set_depot_capacity(
+        ctx: Context<SetDepotCapacity>,
+        max_item_count: u64,
+        max_weight_grams: u64,
+        max_volume_cm3: u64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        set_depot_capacity_handler(
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            max_item_count,
+            max_weight_grams,
+            max_volume_cm3,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct CloseItems<'info> {
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut, close = owner)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn close_items(ctx: Context<CloseItems>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        close_items_handler(owner.clone(), config.clone(), depot.clone(), items.clone());
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (counted_item_count : u64 , commitment : [u8 ; 32])]
+    pub struct Stocktake<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub auditor: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub auditor_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub auditor_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: StocktakeRecord > () + 8 , payer = payer , seeds = [depot . key () . as_ref () , "stocktake" . as_bytes () . as_ref () , depot . stocktake_count . to_le_bytes () . as_ref ()] , bump)]
+        pub stocktake_record: Box<Account<'info, dot::program::StocktakeRecord>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn stocktake(
+        ctx: Context<Stocktake>,
+        counted_item_count: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let auditor = SeahorseSigner {
+            account: &ctx.accounts.auditor,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let auditor_role = ctx
+            .accounts
+            .auditor_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let auditor_hub_depot = ctx
+            .accounts
+            .auditor_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let stocktake_record = Empty {
+            account: dot::program::StocktakeRecord::load(&mut ctx.accounts.stocktake_record, &programs_map),
+            bump: Some(ctx.bumps.stocktake_record),
+        };
+
+        stocktake_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            auditor.clone(),
+            depot.clone(),
+            auditor_role.clone(),
+            auditor_hub_depot.clone(),
+            stocktake_record.clone(),
+            counted_item_count,
+            commitment,
+        );
+
+        dot::program::Depot::store(depot);
+
+        dot::program::StocktakeRecord::store(stocktake_record.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (sub_location_class : SubLocation)]
+    pub struct UpdateItemsSubLocation<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub staff: Signer<'info>,
+        #[account()]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub staff_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub staff_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn update_items_sub_location(
+        ctx: Context<UpdateItemsSubLocation>,
+        sub_location_class: SubLocation,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let staff = SeahorseSigner {
+            account: &ctx.accounts.staff,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let staff_role = ctx
+            .accounts
+            .staff_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let staff_hub_depot = ctx
+            .accounts
+            .staff_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        update_items_sub_location_handler(
+            clock.clone(),
+            config.clone(),
+            staff.clone(),
+            depot.clone(),
+            staff_role.clone(),
+            staff_hub_depot.clone(),
+            items.clone(),
+            sub_location_class,
+        );
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (parent_depot : Pubkey)]
+    pub struct RequestParentDepot<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn request_parent_depot(
+        ctx: Context<RequestParentDepot>,
+        parent_depot: Pubkey,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        request_parent_depot_handler(
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            parent_depot,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct AcceptChildDepot<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub parent_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account(mut)]
+        pub child_depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn accept_child_depot(ctx: Context<AcceptChildDepot>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let parent_depot =
+            dot::program::Depot::load(&mut ctx.accounts.parent_depot, &programs_map);
+
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let child_depot = dot::program::Depot::load(&mut ctx.accounts.child_depot, &programs_map);
+
+        accept_child_depot_handler(
+            config.clone(),
+            authority.clone(),
+            parent_depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            child_depot.clone(),
+        );
+
+        dot::program::Depot::store(parent_depot);
+
+        dot::program::Depot::store(child_depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct DetachChildDepot<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub parent_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub child_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub child_authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub parent_authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub parent_authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn detach_child_depot(ctx: Context<DetachChildDepot>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let parent_depot =
+            dot::program::Depot::load(&mut ctx.accounts.parent_depot, &programs_map);
+
+        let child_depot = dot::program::Depot::load(&mut ctx.accounts.child_depot, &programs_map);
+        let child_authority_role = ctx
+            .accounts
+            .child_authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let parent_authority_role = ctx
+            .accounts
+            .parent_authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let parent_authority_hub_depot = ctx
+            .accounts
+            .parent_authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        detach_child_depot_handler(
+            config.clone(),
+            authority.clone(),
+            parent_depot.clone(),
+            child_depot.clone(),
+            child_authority_role.clone(),
+            parent_authority_role.clone(),
+            parent_authority_hub_depot.clone(),
+        );
+
+        dot::program::Depot::store(parent_depot);
+
+        dot::program::Depot::store(child_depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct SyncDepotRollup<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub child_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub parent_depot: Box<Account<'info, dot::program::Depot>>,
+    }
+
+    pub fn sync_depot_rollup(ctx: Context<SyncDepotRollup>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let child_depot = dot::program::Depot::load(&mut ctx.accounts.child_depot, &programs_map);
+        let parent_depot =
+            dot::program::Depot::load(&mut ctx.accounts.parent_depot, &programs_map);
+
+        sync_depot_rollup_handler(config.clone(), child_depot.clone(), parent_depot.clone());
+
+        dot::program::Depot::store(child_depot);
+
+        dot::program::Depot::store(parent_depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (depot_type : DepotType)]
+    pub struct SetDepotType<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn set_depot_type(
+        ctx: Context<SetDepotType>,
+        depot_type: DepotType,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        set_depot_type_handler(
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            depot_type,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (receiving_hours_class : Option < ReceivingHours > , reject_after_hours : bool)]
+    pub struct SetReceivingHours<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub authority: Signer<'info>,
+        #[account(mut)]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub authority_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub authority_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+    }
+
+    pub fn set_receiving_hours(
+        ctx: Context<SetReceivingHours>,
+        receiving_hours_class: Option<ReceivingHours>,
+        reject_after_hours: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let authority = SeahorseSigner {
+            account: &ctx.accounts.authority,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let authority_role = ctx
+            .accounts
+            .authority_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let authority_hub_depot = ctx
+            .accounts
+            .authority_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        set_receiving_hours_handler(
+            config.clone(),
+            authority.clone(),
+            depot.clone(),
+            authority_role.clone(),
+            authority_hub_depot.clone(),
+            receiving_hours_class,
+            reject_after_hours,
+        );
+
+        dot::program::Depot::store(depot);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (allowed_hs_chapters : [u8 ; 13] , max_transfers_per_day : u32 , expiry_time : i64)]
+    pub struct CreatePartnerAgreement<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub depot_a_owner: Signer<'info>,
+        #[account(mut)]
+        pub depot_b_owner: Signer<'info>,
+        #[account()]
+        pub depot_a: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub depot_b: Box<Account<'info, dot::program::Depot>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: PartnerAgreement > () + 8 , payer = payer , seeds = [depot_a . key () . as_ref () , depot_b . key () . as_ref () , "partner_agreement" . as_bytes () . as_ref ()] , bump)]
+        pub partner_agreement: Box<Account<'info, dot::program::PartnerAgreement>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn create_partner_agreement(
+        ctx: Context<CreatePartnerAgreement>,
+        allowed_hs_chapters: [u8; 13],
+        max_transfers_per_day: u32,
+        expiry_time: i64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let depot_a_owner = SeahorseSigner {
+            account: &ctx.accounts.depot_a_owner,
+            programs: &programs_map,
+        };
+
+        let depot_b_owner = SeahorseSigner {
+            account: &ctx.accounts.depot_b_owner,
+            programs: &programs_map,
+        };
+
+        let depot_a = dot::program::Depot::load(&mut ctx.accounts.depot_a, &programs_map);
+        let depot_b = dot::program::Depot::load(&mut ctx.accounts.depot_b, &programs_map);
+        let partner_agreement = Empty {
+            account: dot::program::PartnerAgreement::load(&mut ctx.accounts.partner_agreement, &programs_map),
+            bump: Some(ctx.bumps.partner_agreement),
+        };
+
+        create_partner_agreement_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            depot_a_owner.clone(),
+            depot_b_owner.clone(),
+            depot_a.clone(),
+            depot_b.clone(),
+            partner_agreement.clone(),
+            allowed_hs_chapters,
+            max_transfers_per_day,
+            expiry_time,
+        );
+
+        dot::program::PartnerAgreement::store(partner_agreement.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct EndPartnerAgreement<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account()]
+        pub depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut, close = owner)]
+        pub partner_agreement: Box<Account<'info, dot::program::PartnerAgreement>>,
+    }
+
+    pub fn end_partner_agreement(ctx: Context<EndPartnerAgreement>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let depot = dot::program::Depot::load(&mut ctx.accounts.depot, &programs_map);
+        let partner_agreement =
+            dot::program::PartnerAgreement::load(&mut ctx.accounts.partner_agreement, &programs_map);
+
+        end_partner_agreement_handler(
+            config.clone(),
+            owner.clone(),
+            depot.clone(),
+            partner_agreement.clone(),
+        );
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (name_array: [u16; 32] , seed_random : u128)]
+    pub struct CreateCarrier<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: Carrier > () + 8 , payer = payer , seeds = [owner . key () . as_ref () , "carrier" . as_bytes () . as_ref () , seed_random . to_le_bytes () . as_ref ()] , bump)]
+        pub carrier: Box<Account<'info, dot::program::Carrier>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn create_carrier(
+        ctx: Context<CreateCarrier>,
+        name_array: [u16; 32],
+        seed_random: u128,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let carrier = Empty {
+            account: dot::program::Carrier::load(&mut ctx.accounts.carrier, &programs_map),
+            bump: Some(ctx.bumps.carrier),
+        };
+
+        create_carrier_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            owner.clone(),
+            carrier.clone(),
+            name_array,
+            seed_random,
+        );
+
+        dot::program::Carrier::store(carrier.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (device : Pubkey)]
+    pub struct RegisterCarrierDevice<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub carrier: Box<Account<'info, dot::program::Carrier>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: CarrierDevice > () + 8 , payer = payer , seeds = [carrier . key () . as_ref () , device . as_ref () , "carrier_device" . as_bytes () . as_ref ()] , bump)]
+        pub carrier_device: Box<Account<'info, dot::program::CarrierDevice>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn register_carrier_device(
+        ctx: Context<RegisterCarrierDevice>,
+        device: Pubkey,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let carrier = dot::program::Carrier::load(&mut ctx.accounts.carrier, &programs_map);
+        let carrier_device = Empty {
+            account: dot::program::CarrierDevice::load(&mut ctx.accounts.carrier_device, &programs_map),
+            bump: Some(ctx.bumps.carrier_device),
+        };
+
+        register_carrier_device_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            owner.clone(),
+            carrier.clone(),
+            carrier_device.clone(),
+            device,
+        );
+
+        dot::program::Carrier::store(carrier);
+
+        dot::program::CarrierDevice::store(carrier_device.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct RevokeCarrierDevice<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub carrier: Box<Account<'info, dot::program::Carrier>>,
+        #[account(mut, close = owner)]
+        pub carrier_device: Box<Account<'info, dot::program::CarrierDevice>>,
+    }
+
+    pub fn revoke_carrier_device(ctx: Context<RevokeCarrierDevice>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let carrier = dot::program::Carrier::load(&mut ctx.accounts.carrier, &programs_map);
+        let carrier_device =
+            dot::program::CarrierDevice::load(&mut ctx.accounts.carrier_device, &programs_map);
+
+        revoke_carrier_device_handler(
+            config.clone(),
+            owner.clone(),
+            carrier.clone(),
+            carrier_device.clone(),
+        );
+
+        dot::program::Carrier::store(carrier);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct HandoverToCarrier<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub old_depot_owner: Signer<'info>,
+        #[account(mut)]
+        pub carrier_owner: Signer<'info>,
+        #[account(mut)]
+        pub old_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub old_depot_owner_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub old_depot_owner_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account()]
+        pub carrier: Box<Account<'info, dot::program::Carrier>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn handover_to_carrier(ctx: Context<HandoverToCarrier>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let old_depot_owner = SeahorseSigner {
+            account: &ctx.accounts.old_depot_owner,
+            programs: &programs_map,
+        };
+
+        let carrier_owner = SeahorseSigner {
+            account: &ctx.accounts.carrier_owner,
+            programs: &programs_map,
+        };
+
+        let old_depot = dot::program::Depot::load(&mut ctx.accounts.old_depot, &programs_map);
+        let old_depot_owner_role = ctx
+            .accounts
+            .old_depot_owner_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let old_depot_owner_hub_depot = ctx
+            .accounts
+            .old_depot_owner_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let carrier = dot::program::Carrier::load(&mut ctx.accounts.carrier, &programs_map);
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        handover_to_carrier_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            old_depot_owner.clone(),
+            carrier_owner.clone(),
+            old_depot.clone(),
+            old_depot_owner_role.clone(),
+            old_depot_owner_hub_depot.clone(),
+            carrier.clone(),
+            items.clone(),
+        );
+
+        dot::program::Depot::store(old_depot);
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (override_compliance : bool)]
+    pub struct DeliverFromCarrier<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        # [account (seeds = ["transfer_rules" . as_bytes () . as_ref ()] , bump)]
+        pub transfer_rules: Box<Account<'info, dot::program::TransferRules>>,
+        #[account(mut)]
+        pub carrier_owner: Signer<'info>,
+        #[account(mut)]
+        pub new_depot_owner: Signer<'info>,
+        #[account()]
+        pub carrier: Box<Account<'info, dot::program::Carrier>>,
+        #[account()]
+        pub pickup_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account(mut)]
+        pub new_depot: Box<Account<'info, dot::program::Depot>>,
+        #[account()]
+        pub new_depot_owner_role: Option<Box<Account<'info, dot::program::DepotRole>>>,
+        #[account()]
+        pub new_depot_owner_hub_depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account()]
+        pub organization: Option<Box<Account<'info, dot::program::Organization>>>,
+        #[account()]
+        pub new_depot_accreditation_authority: Option<Box<Account<'info, dot::program::AccreditationAuthority>>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn deliver_from_carrier(
+        ctx: Context<DeliverFromCarrier>,
+        override_compliance: bool,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let transfer_rules =
+            dot::program::TransferRules::load(&mut ctx.accounts.transfer_rules, &programs_map);
+
+        let carrier_owner = SeahorseSigner {
+            account: &ctx.accounts.carrier_owner,
+            programs: &programs_map,
+        };
+
+        let new_depot_owner = SeahorseSigner {
+            account: &ctx.accounts.new_depot_owner,
+            programs: &programs_map,
+        };
+
+        let carrier = dot::program::Carrier::load(&mut ctx.accounts.carrier, &programs_map);
+        let pickup_depot =
+            dot::program::Depot::load(&mut ctx.accounts.pickup_depot, &programs_map);
+
+        let new_depot = dot::program::Depot::load(&mut ctx.accounts.new_depot, &programs_map);
+        let new_depot_owner_role = ctx
+            .accounts
+            .new_depot_owner_role
+            .as_mut()
+            .map(|account| dot::program::DepotRole::load(account, &programs_map));
+
+        let new_depot_owner_hub_depot = ctx
+            .accounts
+            .new_depot_owner_hub_depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let organization = ctx
+            .accounts
+            .organization
+            .as_mut()
+            .map(|account| dot::program::Organization::load(account, &programs_map));
+
+        let new_depot_accreditation_authority = ctx
+            .accounts
+            .new_depot_accreditation_authority
+            .as_mut()
+            .map(|account| dot::program::AccreditationAuthority::load(account, &programs_map));
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        deliver_from_carrier_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            transfer_rules.clone(),
+            carrier_owner.clone(),
+            new_depot_owner.clone(),
+            carrier.clone(),
+            pickup_depot.clone(),
+            new_depot.clone(),
+            new_depot_owner_role.clone(),
+            new_depot_owner_hub_depot.clone(),
+            organization.clone(),
+            new_depot_accreditation_authority.clone(),
+            items.clone(),
+            override_compliance,
+        );
+
+        dot::program::Depot::store(new_depot);
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (coordinates_class: Coordinates , sensor_reading_class : Option < SensorReading >)]
+    pub struct UpdateItemsLocationByCarrier<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub device: Signer<'info>,
+        #[account(mut)]
+        pub carrier_device: Box<Account<'info, dot::program::CarrierDevice>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        #[account(mut)]
+        pub route_plan: Option<Box<Account<'info, dot::program::RoutePlan>>>,
+    }
+
+    pub fn update_items_location_by_carrier(
+        ctx: Context<UpdateItemsLocationByCarrier>,
+        coordinates_class: Coordinates,
+        sensor_reading_class: Option<SensorReading>,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let device = SeahorseSigner {
+            account: &ctx.accounts.device,
+            programs: &programs_map,
+        };
+
+        let carrier_device =
+            dot::program::CarrierDevice::load(&mut ctx.accounts.carrier_device, &programs_map);
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let route_plan = ctx
+            .accounts
+            .route_plan
+            .as_mut()
+            .map(|account| dot::program::RoutePlan::load(account, &programs_map));
+
+        update_items_location_by_carrier_handler(
+            clock.clone(),
+            config.clone(),
+            device.clone(),
+            carrier_device.clone(),
+            items.clone(),
+            route_plan.clone(),
+            coordinates_class,
+            sensor_reading_class,
+        );
+
+        dot::program::CarrierDevice::store(carrier_device);
+
+        dot::program::Items::store(items);
+
+        if let Some(route_plan) = route_plan {
+            dot::program::RoutePlan::store(route_plan);
+        }
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (waypoints : [Coordinates ; 16] , waypoint_count : u8 , corridor_width_meters : f64)]
+    pub struct FileRoutePlan<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        #[account(mut)]
+        pub payer: Signer<'info>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        # [account (init , space = std :: mem :: size_of :: < dot :: program :: RoutePlan > () + 8 , payer = payer , seeds = [items . key () . as_ref () , "route_plan" . as_bytes () . as_ref ()] , bump)]
+        pub route_plan: Box<Account<'info, dot::program::RoutePlan>>,
+        pub rent: Sysvar<'info, Rent>,
+        pub system_program: Program<'info, System>,
+    }
+
+    pub fn file_route_plan(
+        ctx: Context<FileRoutePlan>,
+        waypoints: [Coordinates; 16],
+        waypoint_count: u8,
+        corridor_width_meters: f64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+
+        programs.insert(
+            "system_program",
+            ctx.accounts.system_program.to_account_info(),
+        );
+
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let payer = SeahorseSigner {
+            account: &ctx.accounts.payer,
+            programs: &programs_map,
+        };
+
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let route_plan = Empty {
+            account: dot::program::RoutePlan::load(&mut ctx.accounts.route_plan, &programs_map),
+            bump: Some(ctx.bumps.route_plan),
+        };
+
+        file_route_plan_handler(
+            clock.clone(),
+            payer.clone(),
+            config.clone(),
+            owner.clone(),
+            items.clone(),
+            route_plan.clone(),
+            waypoints,
+            waypoint_count,
+            corridor_width_meters,
+        );
+
+        dot::program::Items::store(items);
+
+        dot::program::RoutePlan::store(route_plan.account);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct CloseRoutePlan<'info> {
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+        #[account(mut, close = owner)]
+        pub route_plan: Box<Account<'info, dot::program::RoutePlan>>,
+    }
+
+    pub fn close_route_plan(ctx: Context<CloseRoutePlan>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+        let route_plan = dot::program::RoutePlan::load(&mut ctx.accounts.route_plan, &programs_map);
+
+        close_route_plan_handler(config.clone(), owner.clone(), items.clone(), route_plan.clone());
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (destination_depot : Pubkey , delivery_deadline : i64)]
+    pub struct SetDeliveryCommitment<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub owner: Signer<'info>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn set_delivery_commitment(
+        ctx: Context<SetDeliveryCommitment>,
+        destination_depot: Pubkey,
+        delivery_deadline: i64,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let owner = SeahorseSigner {
+            account: &ctx.accounts.owner,
+            programs: &programs_map,
+        };
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        set_delivery_commitment_handler(
+            clock.clone(),
+            config.clone(),
+            owner.clone(),
+            items.clone(),
+            destination_depot,
+            delivery_deadline,
+        );
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    pub struct MarkItemsOverdue<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn mark_items_overdue(ctx: Context<MarkItemsOverdue>) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        mark_items_overdue_handler(clock.clone(), config.clone(), items.clone());
+
+        dot::program::Items::store(items);
+
+        return Ok(());
+    }
+
+    #[derive(Accounts)]
+    # [instruction (coordinates_class: Coordinates , note_hash : Option < [u8 ; 32] >)]
+    pub struct ConfirmDelivery<'info> {
+        #[account()]
+        pub clock: Sysvar<'info, Clock>,
+        # [account (seeds = ["config" . as_bytes () . as_ref ()] , bump)]
+        pub config: Box<Account<'info, dot::program::Config>>,
+        #[account(mut)]
+        pub consignee: Signer<'info>,
+        #[account(mut)]
+        pub depot: Option<Box<Account<'info, dot::program::Depot>>>,
+        #[account(mut)]
+        pub items: Box<Account<'info, dot::program::Items>>,
+    }
+
+    pub fn confirm_delivery(
+        ctx: Context<ConfirmDelivery>,
+        coordinates_class: Coordinates,
+        note_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let mut programs = HashMap::new();
+        let programs_map = ProgramsMap(programs);
+        let clock = &ctx.accounts.clock.clone();
+        let config = dot::program::Config::load(&mut ctx.accounts.config, &programs_map);
+        let consignee = SeahorseSigner {
+            account: &ctx.accounts.consignee,
+            programs: &programs_map,
+        };
+
+        let depot = ctx
+            .accounts
+            .depot
+            .as_mut()
+            .map(|account| dot::program::Depot::load(account, &programs_map));
+
+        let items = dot::program::Items::load(&mut ctx.accounts.items, &programs_map);
+
+        confirm_delivery_handler(
+            clock.clone(),
+            config.clone(),
+            consignee.clone(),
+            depot.clone(),
+            items.clone(),
+            coordinates_class,
+            note_hash,
+        );
+
+        if let Some(depot) = depot {
+            dot::program::Depot::store(depot);
+        }
+
         dot::program::Items::store(items);
 
         return Ok(());
@@ -1,4 +1,10 @@
 # Built with Seahorse v0.2.0
+#
+# This is the original Seahorse program and is kept for reference only.
+# The program in ../anchor is built from its own Cargo.toml and has grown
+# features Seahorse v0.2.0 cannot express (optional accounts, ed25519
+# instruction introspection, return values). Do not regenerate ../anchor
+# from this file.
 
 from seahorse.prelude import *
 